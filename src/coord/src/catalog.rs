@@ -34,6 +34,7 @@ use tracing::{info, trace};
 
 use mz_build_info::DUMMY_BUILD_INFO;
 use mz_dataflow_types::{
+    connections::Connector,
     sinks::{SinkConnector, SinkConnectorBuilder},
     sources::{AwsExternalId, SourceConnector, Timeline},
 };
@@ -48,8 +49,8 @@ use mz_sql::catalog::{
 };
 use mz_sql::names::{DatabaseSpecifier, FullName, PartialName, SchemaName};
 use mz_sql::plan::{
-    CreateIndexPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan,
-    CreateViewPlan, Params, Plan, PlanContext, StatementDesc,
+    CreateConnectionPlan, CreateIndexPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan,
+    CreateTypePlan, CreateViewPlan, Params, Plan, PlanContext, StatementDesc,
 };
 use mz_transform::Optimizer;
 use uuid::Uuid;
@@ -247,7 +248,8 @@ impl CatalogState {
             | CatalogItem::Func(_)
             | CatalogItem::Index(_)
             | CatalogItem::Sink(_)
-            | CatalogItem::Type(_) => false,
+            | CatalogItem::Type(_)
+            | CatalogItem::Connection(_) => false,
         }
     }
 
@@ -311,7 +313,10 @@ impl CatalogState {
                     }
                 }
             }
-            CatalogItem::Func(_) | CatalogItem::Sink(_) | CatalogItem::Type(_) => (),
+            CatalogItem::Func(_)
+            | CatalogItem::Sink(_)
+            | CatalogItem::Type(_)
+            | CatalogItem::Connection(_) => (),
         }
     }
 
@@ -390,6 +395,7 @@ impl CatalogState {
             CatalogItem::Table(_) => Volatile,
             CatalogItem::Type(_) => Unknown,
             CatalogItem::Func(_) => Unknown,
+            CatalogItem::Connection(_) => Unknown,
         }
     }
 
@@ -464,6 +470,7 @@ pub enum CatalogItem {
     Index(Index),
     Type(Type),
     Func(Func),
+    Connection(Connection),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -491,6 +498,7 @@ pub struct Source {
     pub connector: SourceConnector,
     pub persist_details: Option<SerializedSourcePersistDetails>,
     pub desc: RelationDesc,
+    pub depends_on: Vec<GlobalId>,
 }
 
 impl Source {
@@ -510,6 +518,9 @@ pub struct Sink {
     pub envelope: SinkEnvelope,
     pub with_snapshot: bool,
     pub depends_on: Vec<GlobalId>,
+    /// The notices raised while planning the sink, which are reported in
+    /// `mz_sink_notices`.
+    pub notices: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -545,6 +556,12 @@ pub struct Type {
     pub depends_on: Vec<GlobalId>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct Connection {
+    pub create_sql: String,
+    pub connector: Connector,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct Func {
     #[serde(skip)]
@@ -579,6 +596,7 @@ impl CatalogItem {
             CatalogItem::Index(_) => mz_sql::catalog::CatalogItemType::Index,
             CatalogItem::Type(_) => mz_sql::catalog::CatalogItemType::Type,
             CatalogItem::Func(_) => mz_sql::catalog::CatalogItemType::Func,
+            CatalogItem::Connection(_) => mz_sql::catalog::CatalogItemType::Connection,
         }
     }
 
@@ -590,7 +608,8 @@ impl CatalogItem {
             CatalogItem::Func(_)
             | CatalogItem::Index(_)
             | CatalogItem::Sink(_)
-            | CatalogItem::Type(_) => Err(SqlCatalogError::InvalidDependency {
+            | CatalogItem::Type(_)
+            | CatalogItem::Connection(_) => Err(SqlCatalogError::InvalidDependency {
                 name: name.to_string(),
                 typ: self.typ(),
             }),
@@ -611,6 +630,13 @@ impl CatalogItem {
         }
     }
 
+    pub fn connector(&self, name: &FullName) -> Result<&Connector, SqlCatalogError> {
+        match &self {
+            CatalogItem::Connection(connection) => Ok(&connection.connector),
+            _ => Err(SqlCatalogError::UnknownConnection(name.to_string())),
+        }
+    }
+
     /// Collects the identifiers of the dataflows that this item depends
     /// upon.
     pub fn uses(&self) -> &[GlobalId] {
@@ -618,10 +644,11 @@ impl CatalogItem {
             CatalogItem::Func(_) => &[],
             CatalogItem::Index(idx) => &idx.depends_on,
             CatalogItem::Sink(sink) => &sink.depends_on,
-            CatalogItem::Source(_) => &[],
+            CatalogItem::Source(source) => &source.depends_on,
             CatalogItem::Table(table) => &table.depends_on,
             CatalogItem::Type(typ) => &typ.depends_on,
             CatalogItem::View(view) => &view.depends_on,
+            CatalogItem::Connection(_) => &[],
         }
     }
 
@@ -634,7 +661,8 @@ impl CatalogItem {
             | CatalogItem::Source(_)
             | CatalogItem::Table(_)
             | CatalogItem::Type(_)
-            | CatalogItem::View(_) => false,
+            | CatalogItem::View(_)
+            | CatalogItem::Connection(_) => false,
             CatalogItem::Sink(s) => match s.connector {
                 SinkConnectorState::Pending(_) => true,
                 SinkConnectorState::Ready(_) => false,
@@ -703,7 +731,7 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql)?;
                 Ok(CatalogItem::Index(i))
             }
-            CatalogItem::Func(_) | CatalogItem::Type(_) => {
+            CatalogItem::Func(_) | CatalogItem::Type(_) | CatalogItem::Connection(_) => {
                 unreachable!("{}s cannot be renamed", self.typ())
             }
         }
@@ -755,6 +783,12 @@ impl CatalogEntry {
         self.item.source_connector(&self.name)
     }
 
+    /// Returns the [`mz_dataflow_types::connections::Connector`] associated
+    /// with this `CatalogEntry`.
+    pub fn connector(&self) -> Result<&Connector, SqlCatalogError> {
+        self.item.connector(&self.name)
+    }
+
     /// Reports whether this catalog entry is a table.
     pub fn is_table(&self) -> bool {
         matches!(self.item(), CatalogItem::Table(_))
@@ -906,6 +940,7 @@ impl Catalog {
                             },
                             persist_details: None,
                             desc: log.variant.desc(),
+                            depends_on: vec![],
                         }),
                     );
                     let oid = catalog.allocate_oid()?;
@@ -2149,6 +2184,12 @@ impl Catalog {
                 table_persist_name: None,
                 source_persist_details: None,
             },
+            CatalogItem::Connection(connection) => SerializedCatalogItem::V1 {
+                create_sql: connection.create_sql.clone(),
+                eval_env: None,
+                table_persist_name: None,
+                source_persist_details: None,
+            },
             CatalogItem::Func(_) => unreachable!("cannot serialize functions yet"),
         };
         serde_json::to_vec(&item).expect("catalog serialization cannot fail")
@@ -2209,6 +2250,7 @@ impl Catalog {
                     connector: source.connector,
                     persist_details: source_persist_details,
                     desc: source.desc,
+                    depends_on: source.depends_on,
                 })
             }
             Plan::CreateView(CreateViewPlan { view, .. }) => {
@@ -2234,6 +2276,7 @@ impl Catalog {
             Plan::CreateSink(CreateSinkPlan {
                 sink,
                 with_snapshot,
+                notices,
                 ..
             }) => CatalogItem::Sink(Sink {
                 create_sql: sink.create_sql,
@@ -2242,6 +2285,7 @@ impl Catalog {
                 envelope: sink.envelope,
                 with_snapshot,
                 depends_on: sink.depends_on,
+                notices: notices.iter().map(|notice| notice.to_string()).collect(),
             }),
             Plan::CreateType(CreateTypePlan { typ, .. }) => CatalogItem::Type(Type {
                 create_sql: typ.create_sql,
@@ -2251,6 +2295,12 @@ impl Catalog {
                 },
                 depends_on: typ.depends_on,
             }),
+            Plan::CreateConnection(CreateConnectionPlan { connection, .. }) => {
+                CatalogItem::Connection(Connection {
+                    create_sql: connection.create_sql,
+                    connector: connection.connector,
+                })
+            }
             _ => bail!("catalog entry generated inappropriate plan"),
         })
     }
@@ -2785,6 +2835,10 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         Ok(self.source_connector()?)
     }
 
+    fn connector(&self) -> Result<&Connector, SqlCatalogError> {
+        Ok(self.connector()?)
+    }
+
     fn create_sql(&self) -> &str {
         match self.item() {
             CatalogItem::Table(Table { create_sql, .. }) => create_sql,
@@ -2794,6 +2848,7 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
             CatalogItem::Index(Index { create_sql, .. }) => create_sql,
             CatalogItem::Type(Type { create_sql, .. }) => create_sql,
             CatalogItem::Func(_) => "TODO",
+            CatalogItem::Connection(Connection { create_sql, .. }) => create_sql,
         }
     }
 
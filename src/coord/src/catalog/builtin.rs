@@ -1012,6 +1012,16 @@ lazy_static! {
         index_id: GlobalId::System(4006),
         persistent: false,
     };
+    pub static ref MZ_SINK_NOTICES: BuiltinTable = BuiltinTable {
+        name: "mz_sink_notices",
+        schema: MZ_CATALOG_SCHEMA,
+        desc: RelationDesc::empty()
+            .with_column("sink_id", ScalarType::String.nullable(false))
+            .with_column("notice", ScalarType::String.nullable(false)),
+        id: GlobalId::System(4051),
+        index_id: GlobalId::System(4052),
+        persistent: false,
+    };
     pub static ref MZ_AVRO_OCF_SINKS: BuiltinTable = BuiltinTable {
         name: "mz_avro_ocf_sinks",
         schema: MZ_CATALOG_SCHEMA,
@@ -1195,6 +1205,16 @@ lazy_static! {
             index_id: GlobalId::System(4036),
             persistent: false,
     };
+    pub static ref MZ_RANGE_TYPES: BuiltinTable = BuiltinTable {
+        name: "mz_range_types",
+        schema: MZ_CATALOG_SCHEMA,
+        desc: RelationDesc::empty()
+            .with_column("type_id", ScalarType::String.nullable(false))
+            .with_column("subtype_id", ScalarType::String.nullable(false)),
+            id: GlobalId::System(4049),
+            index_id: GlobalId::System(4050),
+            persistent: false,
+    };
     pub static ref MZ_ROLES: BuiltinTable = BuiltinTable {
         name: "mz_roles",
         schema: MZ_CATALOG_SCHEMA,
@@ -1663,6 +1683,7 @@ FROM
             UNION ALL SELECT type_id, 'l' FROM mz_catalog.mz_list_types
             UNION ALL SELECT type_id, 'm' FROM mz_catalog.mz_map_types
             UNION ALL SELECT type_id, 'p' FROM mz_catalog.mz_pseudo_types
+            UNION ALL SELECT type_id, 'r' FROM mz_catalog.mz_range_types
         )
             AS t ON mz_types.id = t.type_id",
     id: GlobalId::System(5019),
@@ -2118,6 +2139,7 @@ lazy_static! {
             Builtin::Table(&MZ_VIEW_KEYS),
             Builtin::Table(&MZ_VIEW_FOREIGN_KEYS),
             Builtin::Table(&MZ_KAFKA_SINKS),
+            Builtin::Table(&MZ_SINK_NOTICES),
             Builtin::Table(&MZ_AVRO_OCF_SINKS),
             Builtin::Table(&MZ_DATABASES),
             Builtin::Table(&MZ_SCHEMAS),
@@ -2133,6 +2155,7 @@ lazy_static! {
             Builtin::Table(&MZ_BASE_TYPES),
             Builtin::Table(&MZ_LIST_TYPES),
             Builtin::Table(&MZ_MAP_TYPES),
+            Builtin::Table(&MZ_RANGE_TYPES),
             Builtin::Table(&MZ_ROLES),
             Builtin::Table(&MZ_PSEUDO_TYPES),
             Builtin::Table(&MZ_FUNCTIONS),
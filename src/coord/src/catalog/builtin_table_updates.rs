@@ -22,7 +22,8 @@ use mz_sql_parser::ast::display::AstDisplay;
 use crate::catalog::builtin::{
     MZ_ARRAY_TYPES, MZ_AVRO_OCF_SINKS, MZ_BASE_TYPES, MZ_COLUMNS, MZ_DATABASES, MZ_FUNCTIONS,
     MZ_INDEXES, MZ_INDEX_COLUMNS, MZ_KAFKA_SINKS, MZ_LIST_TYPES, MZ_MAP_TYPES, MZ_PSEUDO_TYPES,
-    MZ_ROLES, MZ_SCHEMAS, MZ_SINKS, MZ_SOURCES, MZ_TABLES, MZ_TYPES, MZ_VIEWS,
+    MZ_RANGE_TYPES, MZ_ROLES, MZ_SCHEMAS, MZ_SINKS, MZ_SINK_NOTICES, MZ_SOURCES, MZ_TABLES,
+    MZ_TYPES, MZ_VIEWS,
 };
 use crate::catalog::{
     CatalogItem, CatalogState, Func, Index, Sink, SinkConnector, SinkConnectorState, Source, Table,
@@ -114,6 +115,8 @@ impl CatalogState {
             CatalogItem::Sink(sink) => self.pack_sink_update(id, oid, schema_id, name, sink, diff),
             CatalogItem::Type(ty) => self.pack_type_update(id, oid, schema_id, name, ty, diff),
             CatalogItem::Func(func) => self.pack_func_update(id, schema_id, name, func, diff),
+            // Connections are not yet described by any system table.
+            CatalogItem::Connection(_) => vec![],
         };
 
         if let Ok(desc) = entry.desc() {
@@ -276,6 +279,13 @@ impl CatalogState {
                 ]),
                 diff,
             });
+            for notice in &sink.notices {
+                updates.push(BuiltinTableUpdate {
+                    id: MZ_SINK_NOTICES.id,
+                    row: Row::pack_slice(&[Datum::String(&id.to_string()), Datum::String(notice)]),
+                    diff,
+                });
+            }
         }
         updates
     }
@@ -377,6 +387,10 @@ impl CatalogState {
                 vec![id.to_string(), key_id.to_string(), value_id.to_string()],
             ),
             CatalogType::Pseudo => (MZ_PSEUDO_TYPES.id, vec![id.to_string()]),
+            CatalogType::Range { element_id } => (
+                MZ_RANGE_TYPES.id,
+                vec![id.to_string(), element_id.to_string()],
+            ),
             _ => (MZ_BASE_TYPES.id, vec![id.to_string()]),
         };
         let specific_update = BuiltinTableUpdate {
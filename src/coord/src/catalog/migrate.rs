@@ -329,6 +329,7 @@ fn ast_rewrite_pg_catalog_char_to_text_0_9_1(
                 ViewDefinition {
                     name: _,
                     columns: _,
+                    column_types: _,
                     query,
                     with_options: _,
                 },
@@ -497,6 +498,7 @@ fn ast_use_pg_catalog_0_7_1(stmt: &mut mz_sql::ast::Statement<Raw>) -> Result<()
                 ViewDefinition {
                     name: _,
                     columns: _,
+                    column_types: _,
                     query,
                     with_options: _,
                 },
@@ -595,6 +597,7 @@ fn ast_rewrite_type_references_0_6_1(
                 ViewDefinition {
                     name: _,
                     columns: _,
+                    column_types: _,
                     query,
                     with_options: _,
                 },
@@ -649,7 +652,10 @@ fn ast_rewrite_csv_column_aliases_0_9_2(
         if let Statement::CreateSource(CreateSourceStatement {
             connector,
             col_names,
-            format: CreateSourceFormat::Bare(Format::Csv { columns, delimiter }),
+            format:
+                CreateSourceFormat::Bare(Format::Csv {
+                    columns, delimiter, ..
+                }),
             ..
         }) = stmt
         {
@@ -178,6 +178,10 @@ pub enum ExecuteResponse {
         columns: Vec<usize>,
         params: mz_sql::plan::CopyParams,
     },
+    /// The requested connection was created.
+    CreatedConnection {
+        existed: bool,
+    },
     /// The requested database was created.
     CreatedDatabase {
         existed: bool,
@@ -224,6 +228,8 @@ pub enum ExecuteResponse {
     DiscardedTemp,
     /// All state associated with the session has been discarded.
     DiscardedAll,
+    /// The requested connection was dropped.
+    DroppedConnection,
     /// The requested database was dropped.
     DroppedDatabase,
     /// The requested role was dropped.
@@ -135,19 +135,22 @@ use mz_repr::adt::numeric::{Numeric, NumericMaxScale};
 use mz_repr::{Datum, Diff, RelationDesc, RelationType, Row, RowArena, ScalarType, Timestamp};
 use mz_sql::ast::display::AstDisplay;
 use mz_sql::ast::{
-    ConnectorType, CreateIndexStatement, CreateSinkStatement, CreateSourceStatement, ExplainStage,
-    FetchStatement, Ident, InsertSource, ObjectType, Query, Raw, SetExpr, Statement,
+    ConnectorType, CreateConnectionConnector, CreateConnectionStatement, CreateIndexStatement,
+    CreateSinkStatement, CreateSourceStatement, DescribeCreateSinkStatement,
+    ExplainEncodingStatement, ExplainStage, FetchStatement, Ident, InsertSource, ObjectType, Query,
+    Raw, SetExpr, Statement,
 };
 use mz_sql::catalog::{CatalogError, CatalogTypeDetails, SessionCatalog as _};
 use mz_sql::names::{DatabaseSpecifier, FullName};
 use mz_sql::plan::{
     AlterIndexEnablePlan, AlterIndexResetOptionsPlan, AlterIndexSetOptionsPlan,
-    AlterItemRenamePlan, CreateDatabasePlan, CreateIndexPlan, CreateRolePlan, CreateSchemaPlan,
-    CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan,
-    CreateViewsPlan, DropDatabasePlan, DropItemsPlan, DropRolesPlan, DropSchemaPlan, ExecutePlan,
-    ExplainPlan, FetchPlan, HirRelationExpr, IndexOption, IndexOptionName, InsertPlan,
-    MutationKind, Params, PeekPlan, PeekWhen, Plan, ReadThenWritePlan, SendDiffsPlan,
-    SetVariablePlan, ShowVariablePlan, TailFrom, TailPlan,
+    AlterItemRenamePlan, AlterSourceRenameColumnPlan, AlterViewResetOptionsPlan,
+    AlterViewSetOptionsPlan, CreateConnectionPlan, CreateDatabasePlan, CreateIndexPlan,
+    CreateRolePlan, CreateSchemaPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan,
+    CreateTypePlan, CreateViewPlan, CreateViewsPlan, DropDatabasePlan, DropItemsPlan,
+    DropRolesPlan, DropSchemaPlan, ExecutePlan, ExplainPlan, FetchPlan, HirRelationExpr,
+    IndexOption, IndexOptionName, InsertPlan, MutationKind, Params, PeekPlan, PeekWhen, Plan,
+    ReadThenWritePlan, SendDiffsPlan, SetVariablePlan, ShowVariablePlan, TailFrom, TailPlan,
 };
 use mz_sql::plan::{OptimizerConfig, StatementDesc, View};
 use mz_transform::Optimizer;
@@ -1199,9 +1202,11 @@ impl Coordinator {
                                 | Statement::Copy(_)
                                 | Statement::Deallocate(_)
                                 | Statement::Declare(_)
+                                | Statement::DescribeCreateSink(_)
                                 | Statement::Discard(_)
                                 | Statement::Execute(_)
                                 | Statement::Explain(_)
+                                | Statement::ExplainEncoding(_)
                                 | Statement::Fetch(_)
                                 | Statement::Prepare(_)
                                 | Statement::Rollback(_)
@@ -1239,6 +1244,9 @@ impl Coordinator {
                                 // Statements below must by run singly (in Started).
                                 Statement::AlterIndex(_)
                                 | Statement::AlterObjectRename(_)
+                                | Statement::AlterSource(_)
+                                | Statement::AlterView(_)
+                                | Statement::CreateConnection(_)
                                 | Statement::CreateDatabase(_)
                                 | Statement::CreateIndex(_)
                                 | Statement::CreateRole(_)
@@ -1849,6 +1857,9 @@ impl Coordinator {
         plan: Plan,
     ) {
         match plan {
+            Plan::CreateConnection(plan) => {
+                tx.send(self.sequence_create_connection(plan).await, session);
+            }
             Plan::CreateDatabase(plan) => {
                 tx.send(self.sequence_create_database(plan).await, session);
             }
@@ -1968,12 +1979,24 @@ impl Coordinator {
             Plan::AlterItemRename(plan) => {
                 tx.send(self.sequence_alter_item_rename(plan).await, session);
             }
+            Plan::AlterSourceRenameColumn(plan) => {
+                tx.send(
+                    self.sequence_alter_source_rename_column(plan).await,
+                    session,
+                );
+            }
             Plan::AlterIndexSetOptions(plan) => {
                 tx.send(self.sequence_alter_index_set_options(plan), session);
             }
             Plan::AlterIndexResetOptions(plan) => {
                 tx.send(self.sequence_alter_index_reset_options(plan), session);
             }
+            Plan::AlterViewSetOptions(plan) => {
+                tx.send(self.sequence_alter_view_set_options(plan), session);
+            }
+            Plan::AlterViewResetOptions(plan) => {
+                tx.send(self.sequence_alter_view_reset_options(plan), session);
+            }
             Plan::AlterIndexEnable(plan) => {
                 tx.send(self.sequence_alter_index_enable(plan).await, session);
             }
@@ -2093,6 +2116,32 @@ impl Coordinator {
         }
     }
 
+    async fn sequence_create_connection(
+        &mut self,
+        plan: CreateConnectionPlan,
+    ) -> Result<ExecuteResponse, CoordError> {
+        let connection = catalog::Connection {
+            create_sql: plan.connection.create_sql,
+            connector: plan.connection.connector,
+        };
+        let id = self.catalog.allocate_id()?;
+        let oid = self.catalog.allocate_oid()?;
+        let op = catalog::Op::CreateItem {
+            id,
+            oid,
+            name: plan.name,
+            item: CatalogItem::Connection(connection),
+        };
+        match self.catalog_transact(vec![op], |_builder| Ok(())).await {
+            Ok(()) => Ok(ExecuteResponse::CreatedConnection { existed: false }),
+            Err(CoordError::Catalog(catalog::Error {
+                kind: catalog::ErrorKind::ItemAlreadyExists(_),
+                ..
+            })) if plan.if_not_exists => Ok(ExecuteResponse::CreatedConnection { existed: true }),
+            Err(err) => Err(err),
+        }
+    }
+
     async fn sequence_create_database(
         &mut self,
         plan: CreateDatabasePlan,
@@ -2283,9 +2332,10 @@ impl Coordinator {
     async fn sequence_create_source(
         &mut self,
         session: &mut Session,
-        plan: CreateSourcePlan,
+        mut plan: CreateSourcePlan,
     ) -> Result<ExecuteResponse, CoordError> {
         let if_not_exists = plan.if_not_exists;
+        let notices = std::mem::take(&mut plan.notices);
         let (metadata, ops) = self.generate_create_source_ops(session, vec![plan])?;
         match self
             .catalog_transact(ops, move |mut builder| {
@@ -2360,6 +2410,9 @@ impl Coordinator {
                     .await
                     .unwrap();
                 self.ship_dataflows(dfs).await;
+                for notice in notices {
+                    session.add_notice(notice);
+                }
                 Ok(ExecuteResponse::CreatedSource { existed: false })
             }
             Err(CoordError::Catalog(catalog::Error {
@@ -2398,6 +2451,7 @@ impl Coordinator {
                 connector: source.connector,
                 persist_details,
                 desc: source.desc,
+                depends_on: source.depends_on,
             };
             ops.push(catalog::Op::CreateItem {
                 id: source_id,
@@ -2440,7 +2494,7 @@ impl Coordinator {
 
     async fn sequence_create_sink(
         &mut self,
-        session: Session,
+        mut session: Session,
         plan: CreateSinkPlan,
         tx: ClientTransmitter<ExecuteResponse>,
     ) {
@@ -2449,6 +2503,7 @@ impl Coordinator {
             sink,
             with_snapshot,
             if_not_exists,
+            notices,
         } = plan;
 
         // First try to allocate an ID and an OID. If either fails, we're done.
@@ -2484,6 +2539,7 @@ impl Coordinator {
                 envelope: sink.envelope,
                 with_snapshot,
                 depends_on: sink.depends_on,
+                notices: notices.iter().map(|notice| notice.to_string()).collect(),
             }),
         };
 
@@ -2529,6 +2585,10 @@ impl Coordinator {
             }
         }
 
+        for notice in notices {
+            session.add_notice(notice);
+        }
+
         // Now we're ready to create the sink connector. Arrange to notify the
         // main coordinator thread when the future completes.
         let connector_builder = sink.connector_builder;
@@ -2815,7 +2875,8 @@ impl Coordinator {
         plan: DropItemsPlan,
     ) -> Result<ExecuteResponse, CoordError> {
         let ops = self.catalog.drop_items_ops(&plan.items);
-        self.catalog_transact(ops, |_builder| Ok(())).await?;
+        self.catalog_transact_inner(ops, plan.drop_slot, |_builder| Ok(()))
+            .await?;
         Ok(match plan.ty {
             ObjectType::Schema => unreachable!(),
             ObjectType::Source => ExecuteResponse::DroppedSource,
@@ -2824,6 +2885,7 @@ impl Coordinator {
             ObjectType::Sink => ExecuteResponse::DroppedSink,
             ObjectType::Index => ExecuteResponse::DroppedIndex,
             ObjectType::Type => ExecuteResponse::DroppedType,
+            ObjectType::Connection => ExecuteResponse::DroppedConnection,
             ObjectType::Role => unreachable!("DROP ROLE not supported"),
             ObjectType::Object => unreachable!("generic OBJECT cannot be dropped"),
         })
@@ -4078,6 +4140,35 @@ impl Coordinator {
         }
     }
 
+    async fn sequence_alter_source_rename_column(
+        &mut self,
+        plan: AlterSourceRenameColumnPlan,
+    ) -> Result<ExecuteResponse, CoordError> {
+        let mut source = match self.catalog.get_by_id(&plan.id).item() {
+            CatalogItem::Source(source) => source.clone(),
+            _ => unreachable!("planned as a source"),
+        };
+        source.create_sql = plan.create_sql;
+        source.desc = plan.desc;
+        let mut ops = vec![catalog::Op::UpdateItem {
+            id: plan.id,
+            to_item: CatalogItem::Source(source),
+        }];
+        for (id, create_sql) in plan.indexes {
+            let mut index = match self.catalog.get_by_id(&id).item() {
+                CatalogItem::Index(index) => index.clone(),
+                _ => unreachable!("planned as an index"),
+            };
+            index.create_sql = create_sql;
+            ops.push(catalog::Op::UpdateItem {
+                id,
+                to_item: CatalogItem::Index(index),
+            });
+        }
+        self.catalog_transact(ops, |_builder| Ok(())).await?;
+        Ok(ExecuteResponse::AlteredObject(ObjectType::Source))
+    }
+
     fn sequence_alter_index_set_options(
         &mut self,
         plan: AlterIndexSetOptionsPlan,
@@ -4103,6 +4194,35 @@ impl Coordinator {
         Ok(ExecuteResponse::AlteredObject(ObjectType::Index))
     }
 
+    fn sequence_alter_view_set_options(
+        &mut self,
+        plan: AlterViewSetOptionsPlan,
+    ) -> Result<ExecuteResponse, CoordError> {
+        for id in self.catalog.get_indexes_on(plan.id) {
+            self.set_index_options(id, plan.options.clone())?;
+        }
+        Ok(ExecuteResponse::AlteredObject(ObjectType::View))
+    }
+
+    fn sequence_alter_view_reset_options(
+        &mut self,
+        plan: AlterViewResetOptionsPlan,
+    ) -> Result<ExecuteResponse, CoordError> {
+        let options: Vec<_> = plan
+            .options
+            .into_iter()
+            .map(|o| match o {
+                IndexOptionName::LogicalCompactionWindow => IndexOption::LogicalCompactionWindow(
+                    self.logical_compaction_window_ms.map(Duration::from_millis),
+                ),
+            })
+            .collect();
+        for id in self.catalog.get_indexes_on(plan.id) {
+            self.set_index_options(id, options.clone())?;
+        }
+        Ok(ExecuteResponse::AlteredObject(ObjectType::View))
+    }
+
     async fn sequence_alter_index_enable(
         &mut self,
         plan: AlterIndexEnablePlan,
@@ -4135,6 +4255,20 @@ impl Coordinator {
     ///
     /// [`CatalogState`]: crate::catalog::CatalogState
     async fn catalog_transact<F, T>(&mut self, ops: Vec<catalog::Op>, f: F) -> Result<T, CoordError>
+    where
+        F: FnOnce(DataflowBuilder) -> Result<T, CoordError>,
+    {
+        self.catalog_transact_inner(ops, true, f).await
+    }
+
+    /// Like [`Coordinator::catalog_transact`], but allows choosing whether the
+    /// upstream replication slots of dropped Postgres sources are dropped.
+    async fn catalog_transact_inner<F, T>(
+        &mut self,
+        ops: Vec<catalog::Op>,
+        drop_replication_slots: bool,
+        f: F,
+    ) -> Result<T, CoordError>
     where
         F: FnOnce(DataflowBuilder) -> Result<T, CoordError>,
     {
@@ -4152,6 +4286,9 @@ impl Coordinator {
                     }
                     CatalogItem::Source(source) => {
                         sources_to_drop.push(*id);
+                        if !drop_replication_slots {
+                            continue;
+                        }
                         if let SourceConnector::External {
                             connector:
                                 ExternalSourceConnector::Postgres(PostgresSourceConnector {
@@ -4841,12 +4978,33 @@ fn check_statement_safety(stmt: &Statement<Raw>) -> Result<(), CoordError> {
             connector,
             with_options,
             ..
+        })
+        | Statement::ExplainEncoding(ExplainEncodingStatement {
+            stmt:
+                CreateSourceStatement {
+                    connector,
+                    with_options,
+                    ..
+                },
         }) => ("source", ConnectorType::from(connector), with_options),
         Statement::CreateSink(CreateSinkStatement {
             connector,
             with_options,
             ..
+        })
+        | Statement::DescribeCreateSink(DescribeCreateSinkStatement {
+            stmt:
+                CreateSinkStatement {
+                    connector,
+                    with_options,
+                    ..
+                },
         }) => ("sink", ConnectorType::from(connector), with_options),
+        Statement::CreateConnection(CreateConnectionStatement {
+            connector: CreateConnectionConnector::Kafka { .. },
+            with_options,
+            ..
+        }) => ("connection", ConnectorType::Kafka, with_options),
         _ => return Ok(()),
     };
     match typ {
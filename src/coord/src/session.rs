@@ -24,7 +24,7 @@ use mz_expr::GlobalId;
 use mz_pgrepr::Format;
 use mz_repr::{Datum, Diff, Row, ScalarType, Timestamp};
 use mz_sql::ast::{Raw, Statement, TransactionAccessMode};
-use mz_sql::plan::{Params, PlanContext, StatementDesc};
+use mz_sql::plan::{Params, PlanContext, PlanNotice, StatementDesc};
 
 use crate::error::CoordError;
 
@@ -45,6 +45,7 @@ pub struct Session {
     user: String,
     vars: Vars,
     drop_sinks: Vec<GlobalId>,
+    notices: Vec<PlanNotice>,
 }
 
 impl Session {
@@ -72,6 +73,7 @@ impl Session {
             user,
             vars: Vars::default(),
             drop_sinks: vec![],
+            notices: vec![],
         }
     }
 
@@ -216,6 +218,18 @@ impl Session {
         self.drop_sinks.push(name);
     }
 
+    /// Adds a notice to be sent to the client along with the response to the
+    /// current statement.
+    pub fn add_notice(&mut self, notice: PlanNotice) {
+        self.notices.push(notice);
+    }
+
+    /// Removes and returns the notices that have not yet been sent to the
+    /// client.
+    pub fn drain_notices(&mut self) -> Vec<PlanNotice> {
+        mem::take(&mut self.notices)
+    }
+
     /// Sets the transaction ops to `TransactionOps::None`. Must only be used after
     /// verifying that no transaction anomolies will occur if cleared.
     pub fn clear_transaction_ops(&mut self) {
@@ -383,6 +397,7 @@ impl Session {
         let (drop_sinks, _) = self.clear_transaction();
         self.prepared_statements.clear();
         self.vars = Vars::default();
+        self.notices.clear();
         drop_sinks
     }
 
@@ -327,6 +327,7 @@ async fn build_kafka(
         published_schema_info,
         consistency,
         exactly_once: builder.reuse_topic,
+        transactional_id_prefix: builder.transactional_id_prefix,
         transitive_source_dependencies: builder.transitive_source_dependencies,
         fuel: builder.fuel,
         config_options: builder.config_options,
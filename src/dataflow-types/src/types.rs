@@ -450,13 +450,18 @@ pub mod sources {
                     DataEncoding::AvroOcf(AvroOcfEncoding {
                         reader_schema: schema,
                         ..
-                    })
-                    | DataEncoding::Avro(AvroEncoding { schema, .. }) => {
+                    }) => {
                         let parsed_schema =
                             avro::parse_schema(schema).context("validating avro schema")?;
                         avro::schema_to_relationdesc(parsed_schema)
                             .context("validating avro schema")?
                     }
+                    DataEncoding::Avro(encoding) => {
+                        let parsed_schema = avro::parse_schema(encoding.decoding_schema())
+                            .context("validating avro schema")?;
+                        avro::schema_to_relationdesc(parsed_schema)
+                            .context("validating avro schema")?
+                    }
                     DataEncoding::Protobuf(ProtobufEncoding {
                         descriptors,
                         message_name,
@@ -486,22 +491,30 @@ pub mod sources {
                             let ty = ScalarType::String.nullable(true);
                             desc.with_column(name, ty)
                         }),
-                    DataEncoding::Csv(CsvEncoding { columns, .. }) => match columns {
-                        ColumnSpec::Count(n) => {
-                            (1..=*n).into_iter().fold(RelationDesc::empty(), |desc, i| {
-                                desc.with_column(
-                                    format!("column{}", i),
-                                    ScalarType::String.nullable(false),
-                                )
-                            })
+                    DataEncoding::Csv(CsvEncoding {
+                        columns,
+                        null_string,
+                        ..
+                    }) => {
+                        // Fields can only decode to NULL if a sentinel was given.
+                        let nullable = null_string.is_some();
+                        match columns {
+                            ColumnSpec::Count(n) => {
+                                (1..=*n).into_iter().fold(RelationDesc::empty(), |desc, i| {
+                                    desc.with_column(
+                                        format!("column{}", i),
+                                        ScalarType::String.nullable(nullable),
+                                    )
+                                })
+                            }
+                            ColumnSpec::Header { names } => names.iter().map(|s| &**s).fold(
+                                RelationDesc::empty(),
+                                |desc, name| {
+                                    desc.with_column(name, ScalarType::String.nullable(nullable))
+                                },
+                            ),
                         }
-                        ColumnSpec::Header { names } => names
-                            .iter()
-                            .map(|s| &**s)
-                            .fold(RelationDesc::empty(), |desc, name| {
-                                desc.with_column(name, ScalarType::String.nullable(false))
-                            }),
-                    },
+                    }
                     DataEncoding::Text => RelationDesc::empty()
                         .with_column("text", ScalarType::String.nullable(false)),
                     DataEncoding::Postgres => RelationDesc::empty()
@@ -535,8 +548,19 @@ pub mod sources {
         #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
         pub struct AvroEncoding {
             pub schema: String,
+            /// A schema pinned by the user to decode into, if any. Messages
+            /// are resolved from their writer schema into this schema rather
+            /// than into `schema`.
+            pub reader_schema: Option<String>,
             pub schema_registry_config: Option<mz_ccsr::ClientConfig>,
-            pub confluent_wire_format: bool,
+            pub confluent_wire_format: avro::ConfluentWireFormat,
+        }
+
+        impl AvroEncoding {
+            /// Returns the schema that messages are decoded into.
+            pub fn decoding_schema(&self) -> &str {
+                self.reader_schema.as_deref().unwrap_or(&self.schema)
+            }
         }
 
         #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -557,6 +581,36 @@ pub mod sources {
         pub struct CsvEncoding {
             pub columns: ColumnSpec,
             pub delimiter: u8,
+            /// Fields that exactly match this string decode to `NULL`.
+            pub null_string: Option<String>,
+            /// The whitespace to remove from each field, before it is
+            /// compared to `null_string`.
+            pub trim: CsvTrim,
+        }
+
+        /// Determines which whitespace is trimmed from CSV fields.
+        #[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq)]
+        pub enum CsvTrim {
+            /// Fields are left as they are.
+            None,
+            /// Whitespace is trimmed from the start of fields.
+            Leading,
+            /// Whitespace is trimmed from the end of fields.
+            Trailing,
+            /// Whitespace is trimmed from both ends of fields.
+            Both,
+        }
+
+        impl CsvTrim {
+            /// Removes the whitespace selected by `self` from `field`.
+            pub fn apply<'a>(&self, field: &'a str) -> &'a str {
+                match self {
+                    CsvTrim::None => field,
+                    CsvTrim::Leading => field.trim_start(),
+                    CsvTrim::Trailing => field.trim_end(),
+                    CsvTrim::Both => field.trim(),
+                }
+            }
         }
 
         /// Determines the RelationDesc and decoding of CSV objects
@@ -732,6 +786,18 @@ pub mod sources {
         Topic,
     }
 
+    impl std::fmt::Display for IncludedColumnSource {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(match self {
+                IncludedColumnSource::DefaultPosition => "default position",
+                IncludedColumnSource::Partition => "partition",
+                IncludedColumnSource::Offset => "offset",
+                IncludedColumnSource::Timestamp => "timestamp",
+                IncludedColumnSource::Topic => "topic",
+            })
+        }
+    }
+
     /// Whether and how to include the decoded key of a stream in dataflows
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
     pub enum KeyEnvelope {
@@ -747,6 +813,10 @@ pub mod sources {
         /// * For a multi-column key, the columns will get packed into a [`ScalarType::Record`], and
         ///   that Record will get the given name.
         Named(String),
+        /// For composite key encodings, pack the fields from the encoding into
+        /// a single [`ScalarType::Record`] column with the given name, regardless
+        /// of how many fields the key has.
+        Record(String),
     }
 
     /// A column that was created via an `INCLUDE` expression
@@ -814,6 +884,11 @@ pub mod sources {
         /// `ENVELOPE UPSERT`, where the key shape depends on the independent
         /// `KeyEnvelope`
         Default(KeyEnvelope),
+        /// `ENVELOPE UPSERT (VALUE DECODING ERRORS = INLINE)`, which is like
+        /// `Default`, except that a value that fails to decode is reported in
+        /// a trailing `error` column of the key's row instead of failing the
+        /// source
+        ValueErrInline { key_envelope: KeyEnvelope },
         /// `ENVELOPE DEBEZIUM UPSERT`
         Debezium { after_idx: usize },
     }
@@ -965,7 +1040,27 @@ pub mod sources {
         ) -> anyhow::Result<(SourceEnvelope, RelationDesc)> {
             Ok(match &self {
                 UnplannedSourceEnvelope::None(key_envelope)
-                | UnplannedSourceEnvelope::Upsert(UpsertStyle::Default(key_envelope)) => {
+                | UnplannedSourceEnvelope::Upsert(UpsertStyle::Default(key_envelope))
+                | UnplannedSourceEnvelope::Upsert(UpsertStyle::ValueErrInline { key_envelope }) => {
+                    let value_desc = match &self {
+                        UnplannedSourceEnvelope::Upsert(UpsertStyle::ValueErrInline { .. }) => {
+                            if value_desc.get_by_name(&"error".into()).is_some() {
+                                bail!(
+                                    "VALUE DECODING ERRORS = INLINE requires that no value column \
+                                     is named \"error\""
+                                );
+                            }
+                            // The value columns are null in the rows of values that
+                            // failed to decode.
+                            RelationDesc::from_names_and_types(
+                                value_desc
+                                    .iter()
+                                    .map(|(name, typ)| (name.clone(), typ.clone().nullable(true))),
+                            )
+                            .with_column("error", ScalarType::String.nullable(true))
+                        }
+                        _ => value_desc,
+                    };
                     let key_desc = match key_desc {
                         Some(desc) => desc,
                         None => {
@@ -994,10 +1089,12 @@ pub mod sources {
                                 Some(key_indices),
                             )
                         }
-                        KeyEnvelope::Named(key_name) => {
+                        KeyEnvelope::Named(key_name) | KeyEnvelope::Record(key_name) => {
                             let key_desc = {
                                 // if the key has multiple objects, nest them as a record inside of a single name
-                                if key_desc.arity() > 1 {
+                                if key_desc.arity() > 1
+                                    || matches!(key_envelope, KeyEnvelope::Record(_))
+                                {
                                     let key_type = key_desc.typ();
                                     let key_as_record = RelationType::new(vec![ColumnType {
                                         nullable: false,
@@ -1092,6 +1189,32 @@ pub mod sources {
         pub include_topic: Option<IncludedColumnPos>,
         /// If present, include the offset as an output column of the source with the given name.
         pub include_offset: Option<IncludedColumnPos>,
+        /// What to do with records that fail to decode.
+        pub on_decode_error: DecodeErrorPolicy,
+        /// The compression applied by the producer to each message value,
+        /// which must be undone before the value is decoded.
+        pub value_decompression: Compression,
+    }
+
+    /// What a Kafka source does with a record that fails to decode.
+    #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    pub enum DecodeErrorPolicy {
+        /// Surface the record as a decode error in the source.
+        Error,
+        /// Drop the record.
+        Skip,
+        /// Publish the undecoded record to a deadletter topic.
+        Deadletter(KafkaDeadletter),
+    }
+
+    /// The Kafka topic that receives the records a source fails to decode.
+    #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    pub struct KafkaDeadletter {
+        pub addrs: KafkaAddrs,
+        pub topic: String,
+        // Shared with the source unless the deadletter topic lives on a
+        // different cluster, in which case they are configured separately.
+        pub config_options: BTreeMap<String, String>,
     }
 
     /// Legacy logic included something like an offset into almost data streams
@@ -1137,6 +1260,31 @@ pub mod sources {
             metadata_columns: Vec<IncludedColumnSource>,
             ts_frequency: Duration,
             timeline: Timeline,
+            /// The index of a `timestamp` or `timestamptz` column from which
+            /// each row's timestamp is derived, if the source uses event time.
+            ///
+            /// A row is assigned the later of its event time and its ingestion
+            /// time, as timestamps that have already been closed cannot
+            /// receive new rows. Event times that precede the ingestion time
+            /// are therefore not reflected in the row's timestamp.
+            timestamp_column: Option<usize>,
+            /// How far a row's event time may lag the latest event time read
+            /// from its partition before the row is considered too late and
+            /// dropped. Only set alongside `timestamp_column`.
+            max_lateness: Option<Duration>,
+            /// How many records that fail to decode each worker may skip or
+            /// publish to a Kafka source's deadletter topic before the source
+            /// reports decode errors again. Only set alongside
+            /// `DecodeErrorPolicy::Skip` or `DecodeErrorPolicy::Deadletter`.
+            ///
+            /// The count is kept in memory by each worker and starts over
+            /// whenever the source is rendered, e.g. after a restart, so the
+            /// source as a whole may skip or deadletter this many records per
+            /// worker per restart.
+            max_decode_errors: Option<u64>,
+            /// How many records each worker may read from the source per
+            /// second, if limited.
+            max_records_per_second: Option<u64>,
         },
 
         /// A local "source" is either fed by a local input handle, or by reading from a
@@ -1656,6 +1804,8 @@ pub mod sinks {
         pub published_schema_info: Option<PublishedSchemaInfo>,
         pub consistency: Option<KafkaSinkConsistencyConnector>,
         pub exactly_once: bool,
+        // The prefix of the transactional id of exactly-once producers.
+        pub transactional_id_prefix: Option<String>,
         // Source dependencies for exactly-once sinks.
         pub transitive_source_dependencies: Vec<GlobalId>,
         // Maximum number of records the sink will attempt to send each time it is
@@ -1756,6 +1906,9 @@ pub mod sinks {
         // Forces the sink to always write to the same topic across restarts instead
         // of picking a new topic each time.
         pub reuse_topic: bool,
+        // Overrides the prefix of the transactional id the sink's producer
+        // uses when `reuse_topic` is set.
+        pub transactional_id_prefix: Option<String>,
         // Source dependencies for exactly-once sinks.
         pub transitive_source_dependencies: Vec<GlobalId>,
         pub retention: KafkaSinkConnectorRetention,
@@ -1818,3 +1971,27 @@ impl LinearOperator {
         self.predicates.is_empty() && self.projection.iter().copied().eq(0..arity)
     }
 }
+
+/// Types related to named connections to external systems.
+pub mod connections {
+    use std::collections::BTreeMap;
+
+    use serde::{Deserialize, Serialize};
+
+    use mz_kafka_util::KafkaAddrs;
+
+    /// A named connection that sources can reference instead of repeating the
+    /// connection details inline.
+    #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    pub enum Connector {
+        Kafka(KafkaConnector),
+    }
+
+    /// The details necessary to connect to a Kafka cluster.
+    #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    pub struct KafkaConnector {
+        pub addrs: KafkaAddrs,
+        /// Options passed through to librdkafka, e.g. security settings.
+        pub config_options: BTreeMap<String, String>,
+    }
+}
@@ -10,7 +10,7 @@
 use futures::executor::block_on;
 
 use mz_dataflow_types::DecodeError;
-use mz_interchange::avro::Decoder;
+use mz_interchange::avro::{ConfluentWireFormat, Decoder};
 use mz_repr::Row;
 
 #[derive(Debug)]
@@ -25,7 +25,7 @@ impl AvroDecoderState {
         value_schema: &str,
         schema_registry_config: Option<mz_ccsr::ClientConfig>,
         debug_name: String,
-        confluent_wire_format: bool,
+        confluent_wire_format: ConfluentWireFormat,
     ) -> Result<Self, anyhow::Error> {
         Ok(AvroDecoderState {
             decoder: Decoder::new(
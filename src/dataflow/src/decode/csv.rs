@@ -7,13 +7,18 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use mz_dataflow_types::{sources::encoding::CsvEncoding, DecodeError, LinearOperator};
+use mz_dataflow_types::{
+    sources::encoding::{CsvEncoding, CsvTrim},
+    DecodeError, LinearOperator,
+};
 use mz_repr::{Datum, Row};
 
 #[derive(Debug)]
 pub struct CsvDecoderState {
     next_row_is_header: bool,
     header_names: Option<Vec<String>>,
+    null_string: Option<String>,
+    trim: CsvTrim,
     n_cols: usize,
     output: Vec<u8>,
     output_cursor: usize,
@@ -32,7 +37,12 @@ impl CsvDecoderState {
     }
 
     pub fn new(format: CsvEncoding, operators: &mut Option<LinearOperator>) -> Self {
-        let CsvEncoding { columns, delimiter } = format;
+        let CsvEncoding {
+            columns,
+            delimiter,
+            null_string,
+            trim,
+        } = format;
         let n_cols = columns.arity();
 
         let operators = operators.take();
@@ -49,6 +59,8 @@ impl CsvDecoderState {
         Self {
             next_row_is_header: header_names.is_some(),
             header_names,
+            null_string,
+            trim,
             n_cols,
             output: vec![0],
             output_cursor: 0,
@@ -109,14 +121,24 @@ impl CsvDecoderState {
                                 Ok(output) => {
                                     self.events_success += 1;
                                     let mut row_packer = std::mem::take(&mut self.row_packer);
+                                    let null_string = self.null_string.as_deref();
                                     row_packer.extend((0..self.n_cols).map(|i| {
-                                        Datum::String(
-                                            if self.next_row_is_header || self.demanded[i] {
-                                                &output[self.ends[i]..self.ends[i + 1]]
-                                            } else {
-                                                ""
-                                            },
-                                        )
+                                        if self.next_row_is_header {
+                                            return Datum::String(
+                                                &output[self.ends[i]..self.ends[i + 1]],
+                                            );
+                                        }
+                                        if !self.demanded[i] {
+                                            return Datum::String("");
+                                        }
+                                        let field = self
+                                            .trim
+                                            .apply(&output[self.ends[i]..self.ends[i + 1]]);
+                                        if Some(field) == null_string {
+                                            Datum::Null
+                                        } else {
+                                            Datum::String(field)
+                                        }
                                     }));
                                     self.row_packer = row_packer;
                                     self.output_cursor = 0;
@@ -0,0 +1,162 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rdkafka::client::ClientContext;
+use rdkafka::config::ClientConfig;
+use rdkafka::error::KafkaError;
+use rdkafka::producer::{BaseRecord, DeliveryResult, Producer, ProducerContext, ThreadedProducer};
+use tracing::error;
+
+use mz_dataflow_types::sources::KafkaDeadletter;
+use mz_kafka_util::client::MzClientContext;
+
+/// How long to wait, once a source is dropped, for the records it has sent to
+/// its deadletter topic to be delivered.
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Publishes the records a source fails to decode to its deadletter topic.
+///
+/// Records are sent asynchronously. A record only counts as deadlettered
+/// once the broker confirms its delivery, and the records still awaiting
+/// delivery are flushed when the producer is dropped. The flush happens on a
+/// thread of its own, so that dropping the producer does not block the timely
+/// worker that owns it.
+pub struct DeadletterProducer {
+    producer: Arc<ThreadedProducer<DeadletterProducerContext>>,
+    topic: String,
+}
+
+impl DeadletterProducer {
+    pub fn new(deadletter: &KafkaDeadletter, debug_name: &str) -> Result<Self, KafkaError> {
+        let mut config = ClientConfig::new();
+        config.set("bootstrap.servers", &deadletter.addrs.to_string());
+        for (k, v) in deadletter.config_options.iter() {
+            // As with Kafka sinks, skip options that are consumer-only or that
+            // would flood the log.
+            if k != "statistics.interval.ms" && k != "isolation.level" {
+                config.set(k, v);
+            }
+        }
+        let producer =
+            config.create_with_context::<_, ThreadedProducer<_>>(DeadletterProducerContext {
+                debug_name: debug_name.to_string(),
+                delivered: AtomicU64::new(0),
+                in_flight: AtomicU64::new(0),
+            })?;
+        Ok(DeadletterProducer {
+            producer: Arc::new(producer),
+            topic: deadletter.topic.clone(),
+        })
+    }
+
+    /// The topic the records are published to.
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// The number of records whose delivery the broker has confirmed, plus
+    /// those still awaiting delivery.
+    ///
+    /// Records whose delivery fails are not counted.
+    pub fn n_deadlettered(&self) -> u64 {
+        let context = self.producer.context();
+        context.delivered.load(Ordering::SeqCst) + context.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Sends a record with the given `key` and `value` to the deadletter
+    /// topic.
+    pub fn send(&self, key: Option<&[u8]>, value: Option<&[u8]>) -> Result<(), KafkaError> {
+        let mut record = BaseRecord::<[u8], [u8]>::to(&self.topic);
+        if let Some(key) = key {
+            record = record.key(key);
+        }
+        if let Some(value) = value {
+            record = record.payload(value);
+        }
+        let in_flight = &self.producer.context().in_flight;
+        in_flight.fetch_add(1, Ordering::SeqCst);
+        self.producer.send(record).map_err(|(e, _)| {
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+            e
+        })
+    }
+}
+
+impl Drop for DeadletterProducer {
+    fn drop(&mut self) {
+        // The flushing thread holds the last reference to the producer, which
+        // it releases once the pending records are delivered or the flush
+        // times out.
+        let producer = Arc::clone(&self.producer);
+        let topic = self.topic.clone();
+        let spawned = std::thread::Builder::new()
+            .name("deadletter-flush".to_string())
+            .spawn(move || {
+                if let Err(e) = producer.flush(FLUSH_TIMEOUT) {
+                    error!(
+                        "{}: unable to flush records to deadletter topic {}: {}",
+                        producer.context().debug_name,
+                        topic,
+                        e
+                    );
+                }
+            });
+        if let Err(e) = spawned {
+            error!(
+                "{}: unable to flush records to deadletter topic {}: {}",
+                self.producer.context().debug_name,
+                self.topic,
+                e
+            );
+        }
+    }
+}
+
+struct DeadletterProducerContext {
+    debug_name: String,
+    /// The number of records whose delivery the broker has confirmed.
+    delivered: AtomicU64,
+    /// The number of records that have been sent but whose delivery has not
+    /// yet been reported.
+    in_flight: AtomicU64,
+}
+
+impl ClientContext for DeadletterProducerContext {
+    // The shape of the rdkafka *Context traits require us to forward to the `MzClientContext`
+    // implementation.
+    fn log(&self, level: rdkafka::config::RDKafkaLogLevel, fac: &str, log_message: &str) {
+        MzClientContext.log(level, fac, log_message)
+    }
+    fn error(&self, error: KafkaError, reason: &str) {
+        MzClientContext.error(error, reason)
+    }
+}
+
+impl ProducerContext for DeadletterProducerContext {
+    type DeliveryOpaque = ();
+
+    fn delivery(&self, result: &DeliveryResult, _: Self::DeliveryOpaque) {
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        match result {
+            Ok(_) => {
+                self.delivered.fetch_add(1, Ordering::SeqCst);
+            }
+            Err((e, msg)) => error!(
+                "{}: unable to deliver record to deadletter topic {}: {}",
+                self.debug_name,
+                msg.topic(),
+                e
+            ),
+        }
+    }
+}
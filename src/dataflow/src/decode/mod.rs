@@ -7,6 +7,8 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::borrow::Cow;
+use std::io::Read;
 use std::{any::Any, cell::RefCell, collections::VecDeque, rc::Rc, time::Duration};
 
 use ::regex::Regex;
@@ -14,6 +16,7 @@ use chrono::NaiveDateTime;
 use differential_dataflow::capture::YieldingIter;
 use differential_dataflow::Hashable;
 use differential_dataflow::{AsCollection, Collection};
+use flate2::read::MultiGzDecoder;
 use futures::executor::block_on;
 use mz_avro::{AvroDeserializer, GeneralDeserializer};
 use mz_expr::PartitionId;
@@ -26,30 +29,32 @@ use timely::scheduling::SyncActivator;
 use mz_dataflow_types::{
     sources::{
         encoding::{AvroEncoding, AvroOcfEncoding, DataEncoding, RegexEncoding},
-        IncludedColumnSource, SourceEnvelope,
+        Compression, DecodeErrorPolicy, IncludedColumnSource, SourceEnvelope,
     },
     DecodeError, LinearOperator,
 };
-use mz_interchange::avro::ConfluentAvroResolver;
+use mz_interchange::avro::{ConfluentAvroResolver, ConfluentWireFormat};
 use mz_repr::Datum;
 use mz_repr::{Diff, Row, Timestamp};
 use tracing::error;
 
 use self::avro::AvroDecoderState;
 use self::csv::CsvDecoderState;
+use self::deadletter::DeadletterProducer;
 use self::protobuf::ProtobufDecoderState;
 use crate::metrics::Metrics;
 use crate::source::{DecodeResult, SourceOutput};
 
 mod avro;
 mod csv;
+mod deadletter;
 mod protobuf;
 
 pub fn decode_cdcv2<G: Scope<Timestamp = Timestamp>>(
     stream: &Stream<G, SourceOutput<Option<Vec<u8>>, Option<Vec<u8>>>>,
     schema: &str,
     registry: Option<mz_ccsr::ClientConfig>,
-    confluent_wire_format: bool,
+    confluent_wire_format: ConfluentWireFormat,
 ) -> (Collection<G, Row, Diff>, Box<dyn Any>) {
     // We will have already checked validity of the schema by now, so this can't fail.
     let mut resolver = ConfluentAvroResolver::new(schema, registry, confluent_wire_format).unwrap();
@@ -70,21 +75,22 @@ pub fn decode_cdcv2<G: Scope<Timestamp = Timestamp>>(
                             Some(value) => value,
                             None => continue,
                         };
-                        let (mut data, schema, _) = match block_on(resolver.resolve(&*value)) {
+                        let message = block_on(resolver.resolve_and_decode(
+                            &mut &value[..],
+                            |data, schema, _| {
+                                let d = GeneralDeserializer {
+                                    schema: schema.top_node(),
+                                };
+                                let dec = mz_interchange::avro::cdc_v2::Decoder;
+                                d.deserialize(data, dec).map_err(|e| {
+                                    anyhow::anyhow!("Failed to deserialize avro message: {}", e)
+                                })
+                            },
+                        ));
+                        let message = match message {
                             Ok(ok) => ok,
                             Err(e) => {
-                                error!("Failed to get schema info for CDCv2 record: {}", e);
-                                continue;
-                            }
-                        };
-                        let d = GeneralDeserializer {
-                            schema: schema.top_node(),
-                        };
-                        let dec = mz_interchange::avro::cdc_v2::Decoder;
-                        let message = match d.deserialize(&mut data, dec) {
-                            Ok(ok) => ok,
-                            Err(e) => {
-                                error!("Failed to deserialize avro message: {}", e);
+                                error!("Failed to decode CDCv2 record: {}", e);
                                 continue;
                             }
                         };
@@ -240,11 +246,12 @@ fn get_decoder(
     match encoding {
         DataEncoding::Avro(AvroEncoding {
             schema,
+            reader_schema,
             schema_registry_config,
             confluent_wire_format,
         }) => {
             let state = avro::AvroDecoderState::new(
-                &schema,
+                reader_schema.as_deref().unwrap_or(&schema),
                 schema_registry_config,
                 debug_name.to_string(),
                 confluent_wire_format,
@@ -284,9 +291,13 @@ fn get_decoder(
             DataDecoder { inner, metrics }
         }
         DataEncoding::AvroOcf(AvroOcfEncoding { reader_schema }) => {
-            let state =
-                avro::AvroDecoderState::new(&reader_schema, None, debug_name.to_string(), false)
-                    .expect("Schema was verified to be correct during purification");
+            let state = avro::AvroDecoderState::new(
+                &reader_schema,
+                None,
+                debug_name.to_string(),
+                ConfluentWireFormat::Disabled,
+            )
+            .expect("Schema was verified to be correct during purification");
             DataDecoder {
                 inner: DataDecoderInner::Avro(state),
                 metrics,
@@ -323,6 +334,23 @@ fn try_decode(
         .or_else(|| decoder.eof(&mut &[][..]).transpose())
 }
 
+/// Removes `compression` from a record's value.
+fn decompress(
+    compression: Compression,
+    value: Option<&Vec<u8>>,
+) -> Result<Option<Cow<Vec<u8>>>, DecodeError> {
+    match (compression, value) {
+        (Compression::None, value) | (_, value @ None) => Ok(value.map(Cow::Borrowed)),
+        (Compression::Gzip, Some(value)) => {
+            let mut buf = vec![];
+            MultiGzDecoder::new(value.as_slice())
+                .read_to_end(&mut buf)
+                .map_err(|e| DecodeError::Text(format!("Failed to decompress value: {}", e)))?;
+            Ok(Some(Cow::Owned(buf)))
+        }
+    }
+}
+
 /// Decode already delimited records of data.
 ///
 /// Precondition: each record in the stream has at most one key and at most one value.
@@ -347,6 +375,14 @@ pub fn render_decode_delimited<G>(
     // `None`.
     operators: &mut Option<LinearOperator>,
     metrics: Metrics,
+    // What to do with records that fail to decode.
+    on_decode_error: DecodeErrorPolicy,
+    // If present, the number of records that fail to decode each worker may
+    // skip or publish to the deadletter topic. Records that fail to decode
+    // beyond that are emitted as errors.
+    max_decode_errors: Option<u64>,
+    // The compression to remove from each value before decoding it.
+    value_decompression: Compression,
 ) -> (Stream<G, DecodeResult>, Option<Box<dyn Any>>)
 where
     G: Scope,
@@ -365,6 +401,25 @@ where
 
     let mut value_decoder = get_decoder(value_encoding, debug_name, operators, true, metrics);
 
+    let skip = on_decode_error == DecodeErrorPolicy::Skip;
+    let mut n_skipped = 0;
+    let deadletter = match on_decode_error {
+        DecodeErrorPolicy::Deadletter(deadletter) => {
+            match DeadletterProducer::new(&deadletter, debug_name) {
+                Ok(producer) => Some(producer),
+                Err(e) => {
+                    error!(
+                        "{}: unable to create producer for deadletter topic {}: {}",
+                        debug_name, deadletter.topic, e
+                    );
+                    None
+                }
+            }
+        }
+        DecodeErrorPolicy::Error | DecodeErrorPolicy::Skip => None,
+    };
+    let debug_name = debug_name.to_string();
+
     let dist: fn(&SourceOutput<Option<Vec<u8>>, Option<Vec<u8>>>) -> _ = match envelope {
         SourceEnvelope::Debezium(_) => |x| x.partition.hashed(),
         _ => |x| x.position.hashed(),
@@ -383,21 +438,49 @@ where
                     partition,
                 } in data.iter()
                 {
-                    let key = key_decoder
+                    let decoded_key = key_decoder
                         .as_mut()
                         .and_then(|decoder| try_decode(decoder, key.as_ref()));
 
-                    let value = try_decode(&mut value_decoder, value.as_ref());
+                    let decoded_value = match decompress(value_decompression, value.as_ref()) {
+                        Ok(value) => try_decode(&mut value_decoder, value.as_deref()),
+                        Err(e) => Some(Err(e)),
+                    };
 
-                    if matches!(&key, Some(Err(_))) || matches!(&value, Some(Err(_))) {
+                    if matches!(&decoded_key, Some(Err(_)))
+                        || matches!(&decoded_value, Some(Err(_)))
+                    {
                         n_errors += 1;
-                    } else if matches!(&value, Some(Ok(_))) {
+                        // Once `max_decode_errors` records have been skipped
+                        // or absorbed by the deadletter topic, further
+                        // failures are emitted as errors, failing the source.
+                        // Records whose delivery to the deadletter topic is
+                        // still pending count against the limit.
+                        if skip {
+                            if max_decode_errors.map_or(true, |max| n_skipped < max) {
+                                n_skipped += 1;
+                                continue;
+                            }
+                        } else if let Some(producer) = deadletter.as_ref().filter(|producer| {
+                            max_decode_errors.map_or(true, |max| producer.n_deadlettered() < max)
+                        }) {
+                            match producer.send(key.as_deref(), value.as_deref()) {
+                                Ok(()) => continue,
+                                Err(e) => error!(
+                                    "{}: unable to send record to deadletter topic {}: {}",
+                                    debug_name,
+                                    producer.topic(),
+                                    e
+                                ),
+                            }
+                        }
+                    } else if matches!(&decoded_value, Some(Ok(_))) {
                         n_successes += 1;
                     }
 
                     session.give(DecodeResult {
-                        key,
-                        value,
+                        key: decoded_key,
+                        value: decoded_value,
                         position: *position,
                         upstream_time_millis: *upstream_time_millis,
                         partition: partition.clone(),
@@ -12,12 +12,14 @@
 use std::any::Any;
 use std::collections::HashMap;
 use std::rc::{Rc, Weak};
+use std::time::Duration;
 
 use differential_dataflow::lattice::Lattice;
 use differential_dataflow::{collection, AsCollection, Collection, Hashable};
 use serde::{Deserialize, Serialize};
+use timely::dataflow::channels::pact::Pipeline;
 use timely::dataflow::operators::generic::operator;
-use timely::dataflow::operators::{Concat, Map, OkErr, Probe, UnorderedInput};
+use timely::dataflow::operators::{Concat, Map, OkErr, Operator, Probe, UnorderedInput};
 use timely::dataflow::{ProbeHandle, Scope, Stream};
 use tracing::debug;
 
@@ -30,7 +32,7 @@ use mz_persist_types::Codec;
 use mz_dataflow_types::sources::{encoding::*, persistence::*, *};
 use mz_dataflow_types::*;
 use mz_expr::{GlobalId, PartitionId, SourceInstanceId};
-use mz_repr::{Diff, Row, Timestamp};
+use mz_repr::{Datum, Diff, Row, Timestamp};
 use timely::progress::Antichain;
 
 use crate::decode::decode_cdcv2;
@@ -186,6 +188,10 @@ where
             metadata_columns,
             ts_frequency,
             timeline: _,
+            timestamp_column,
+            max_lateness,
+            max_decode_errors,
+            max_records_per_second,
         } => {
             // TODO(benesch): this match arm is hard to follow. Refactor.
 
@@ -238,6 +244,7 @@ where
                 active: active_read_worker,
                 timestamp_histories,
                 timestamp_frequency: ts_frequency,
+                max_records_per_second,
                 worker_id: scope.index(),
                 worker_count: scope.peers(),
                 logger: materialized_logging,
@@ -350,6 +357,7 @@ where
                     if let SourceEnvelope::CdcV2 = &envelope {
                         let AvroEncoding {
                             schema,
+                            reader_schema,
                             schema_registry_config,
                             confluent_wire_format,
                         } = match value_encoding {
@@ -364,13 +372,19 @@ where
                         // made to work with a stream of Rows instead of decoding Avro directly
                         let (oks, token) = decode_cdcv2(
                             &ok_source,
-                            &schema,
+                            reader_schema.as_deref().unwrap_or(&schema),
                             schema_registry_config,
                             confluent_wire_format,
                         );
                         needed_tokens.push(Rc::new(token));
                         (oks, None)
                     } else {
+                        let (on_decode_error, value_decompression) = match &connector {
+                            ExternalSourceConnector::Kafka(kc) => {
+                                (kc.on_decode_error.clone(), kc.value_decompression)
+                            }
+                            _ => (DecodeErrorPolicy::Error, Compression::None),
+                        };
                         let (results, extra_token) = match ok_source {
                             SourceType::Delimited(source) => render_decode_delimited(
                                 &source,
@@ -381,6 +395,9 @@ where
                                 metadata_columns,
                                 &mut linear_operators,
                                 storage_state.metrics.clone(),
+                                on_decode_error,
+                                max_decode_errors,
+                                value_decompression,
                             ),
                             SourceType::ByteStream(source) => render_decode(
                                 &source,
@@ -439,6 +456,11 @@ where
                                         UpsertStyle::Default(_) => source_persist_config
                                             .as_ref()
                                             .map(|config| config.upsert_config().clone()),
+                                        UpsertStyle::ValueErrInline { .. } => {
+                                            // The persisted upsert state cannot
+                                            // represent the inlined decoding errors.
+                                            None
+                                        }
                                         UpsertStyle::Debezium { .. } => {
                                             // TODO(guswynn): make debezium upsert work with
                                             // persistence. See
@@ -492,6 +514,20 @@ where
                                 let flattened_stream =
                                     flatten_results_prepend_keys(key_envelope, results);
 
+                                // With a lateness bound, a row whose event time
+                                // trails the latest event time of its partition
+                                // by more than the bound is dropped. This
+                                // happens before the rows are persisted, so
+                                // that they are not replayed.
+                                let flattened_stream = match (timestamp_column, max_lateness) {
+                                    (Some(timestamp_column), Some(max_lateness)) => drop_late_rows(
+                                        &flattened_stream,
+                                        timestamp_column,
+                                        max_lateness,
+                                    ),
+                                    _ => flattened_stream.map(|(_, result)| result),
+                                };
+
                                 let flattened_stream = flattened_stream.pass_through("decode");
 
                                 // When persistence is enabled we need to persist and seal up
@@ -573,6 +609,23 @@ where
             use timely::dataflow::operators::Exchange;
             collection = collection.inner.exchange(|x| x.hashed()).as_collection();
 
+            // Derive timestamps from the event time column, if requested. This
+            // must happen before the linear operators, which may blank out the
+            // column. Timestamps are only ever advanced, so rows whose event
+            // time precedes their ingestion time, or is null, keep the latter.
+            if let Some(timestamp_column) = timestamp_column {
+                collection = collection
+                    .inner
+                    .map_in_place(move |(row, time, _)| {
+                        if let Some(Ok(event_time)) =
+                            event_time(row, timestamp_column).map(Timestamp::try_from)
+                        {
+                            *time = std::cmp::max(*time, event_time);
+                        }
+                    })
+                    .as_collection();
+            }
+
             // Implement source filtering and projection.
             // At the moment this is strictly optional, but we perform it anyhow
             // to demonstrate the intended use.
@@ -981,6 +1034,7 @@ impl BoundedCompactionHandle {
 struct KV {
     key: Option<Result<Row, DecodeError>>,
     val: Option<Result<Row, DecodeError>>,
+    partition: PartitionId,
 }
 
 fn append_metadata_to_value<G>(
@@ -1000,7 +1054,11 @@ where
             })
         });
 
-        KV { val, key: res.key }
+        KV {
+            val,
+            key: res.key,
+            partition: res.partition,
+        }
     })
 }
 
@@ -1017,18 +1075,30 @@ where
         UpsertEnvelope {
             style:
                 UpsertStyle::Default(KeyEnvelope::LegacyUpsert | KeyEnvelope::Flattened)
+                | UpsertStyle::ValueErrInline {
+                    key_envelope: KeyEnvelope::LegacyUpsert | KeyEnvelope::Flattened,
+                }
                 | UpsertStyle::Debezium { .. },
             ..
         } => results,
         UpsertEnvelope {
-            style: UpsertStyle::Default(KeyEnvelope::Named(_)),
+            style:
+                UpsertStyle::Default(key_envelope @ KeyEnvelope::Named(_))
+                | UpsertStyle::Default(key_envelope @ KeyEnvelope::Record(_))
+                | UpsertStyle::ValueErrInline {
+                    key_envelope: key_envelope @ KeyEnvelope::Named(_),
+                }
+                | UpsertStyle::ValueErrInline {
+                    key_envelope: key_envelope @ KeyEnvelope::Record(_),
+                },
             ..
         } => {
+            let as_record = matches!(key_envelope, KeyEnvelope::Record(_));
             let mut row_packer = mz_repr::Row::default();
             results.map(move |mut res| {
                 res.key = res.key.map(|k_result| {
                     k_result.map(|k| {
-                        if k.iter().nth(1).is_none() {
+                        if !as_record && k.iter().nth(1).is_none() {
                             k
                         } else {
                             row_packer.clear();
@@ -1042,7 +1112,11 @@ where
             })
         }
         UpsertEnvelope {
-            style: UpsertStyle::Default(KeyEnvelope::None),
+            style:
+                UpsertStyle::Default(KeyEnvelope::None)
+                | UpsertStyle::ValueErrInline {
+                    key_envelope: KeyEnvelope::None,
+                },
             ..
         } => {
             unreachable!("SourceEnvelope::Upsert should never have KeyEnvelope::None")
@@ -1051,31 +1125,38 @@ where
 }
 
 /// Convert from streams of [`DecodeResult`] to Rows, inserting the Key according to [`KeyEnvelope`]
+///
+/// Each row is paired with the partition it came from.
 fn flatten_results_prepend_keys<G>(
     key_envelope: &KeyEnvelope,
     results: timely::dataflow::Stream<G, KV>,
-) -> timely::dataflow::Stream<G, Result<Row, DecodeError>>
+) -> timely::dataflow::Stream<G, (PartitionId, Result<Row, DecodeError>)>
 where
     G: Scope<Timestamp = Timestamp>,
 {
     match key_envelope {
-        KeyEnvelope::None => results.flat_map(|KV { val, .. }| val),
+        KeyEnvelope::None => {
+            results.flat_map(|KV { val, partition, .. }| val.map(|val| (partition, val)))
+        }
         KeyEnvelope::Flattened | KeyEnvelope::LegacyUpsert => results
             .flat_map(raise_key_value_errors)
-            .map(move |maybe_kv| {
-                maybe_kv.map(|(mut key, value)| {
+            .map(move |(partition, maybe_kv)| {
+                let row = maybe_kv.map(|(mut key, value)| {
                     key.extend_by_row(&value);
                     key
-                })
+                });
+                (partition, row)
             }),
-        KeyEnvelope::Named(_) => {
+        KeyEnvelope::Named(_) | KeyEnvelope::Record(_) => {
+            // Record semantics always encode the key as a struct
+            let as_record = matches!(key_envelope, KeyEnvelope::Record(_));
             results
                 .flat_map(raise_key_value_errors)
-                .map(move |maybe_kv| {
-                    maybe_kv.map(|(mut key, value)| {
+                .map(move |(partition, maybe_kv)| {
+                    let row = maybe_kv.map(|(mut key, value)| {
                         // Named semantics rename a key that is a single column, and encode a
                         // multi-column field as a struct with that name
-                        let row = if key.iter().nth(1).is_none() {
+                        let row = if !as_record && key.iter().nth(1).is_none() {
                             key.extend_by_row(&value);
                             key
                         } else {
@@ -1085,15 +1166,22 @@ where
                             new_row
                         };
                         row
-                    })
+                    });
+                    (partition, row)
                 })
         }
     }
 }
 
 /// Handle possibly missing key or value portions of messages
-fn raise_key_value_errors(KV { key, val }: KV) -> Option<Result<(Row, Row), DecodeError>> {
-    match (key, val) {
+fn raise_key_value_errors(
+    KV {
+        key,
+        val,
+        partition,
+    }: KV,
+) -> Option<(PartitionId, Result<(Row, Row), DecodeError>)> {
+    let result = match (key, val) {
         (Some(key), Some(value)) => match (key, value) {
             (Ok(key), Ok(value)) => Some(Ok((key, value))),
             // always prioritize the value error if either or both have an error
@@ -1105,5 +1193,59 @@ fn raise_key_value_errors(KV { key, val }: KV) -> Option<Result<(Row, Row), Deco
         _ => Some(Err(DecodeError::Text(
             "Key or Value are not present for message".to_string(),
         ))),
+    };
+    result.map(|result| (partition, result))
+}
+
+/// Drops the rows of `stream` whose event time, as read from their
+/// `timestamp_column`, trails the latest event time seen in their partition by
+/// more than `max_lateness`.
+///
+/// Each partition is read in order, so which of its rows are dropped depends
+/// only on its contents, and not on when it is read. The latest event times
+/// are not persisted, however, so a partition whose reading resumes part way
+/// through is tracked afresh. Errors and rows with a null event time are
+/// passed through, and do not advance the latest event time.
+fn drop_late_rows<G>(
+    stream: &Stream<G, (PartitionId, Result<Row, DecodeError>)>,
+    timestamp_column: usize,
+    max_lateness: Duration,
+) -> Stream<G, Result<Row, DecodeError>>
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    let max_lateness = i64::try_from(max_lateness.as_millis()).unwrap_or(i64::MAX);
+    stream.unary(Pipeline, "DropLateRows", move |_, _| {
+        // The latest event time seen in each partition.
+        let mut latest_event_times = HashMap::new();
+        let mut data = vec![];
+        move |input, output| {
+            while let Some((cap, refmut_data)) = input.next() {
+                let mut session = output.session(&cap);
+                refmut_data.swap(&mut data);
+                for (partition, result) in data.drain(..) {
+                    if let Ok(row) = &result {
+                        if let Some(event_time) = event_time(row, timestamp_column) {
+                            let latest = latest_event_times.entry(partition).or_insert(event_time);
+                            if event_time < latest.saturating_sub(max_lateness) {
+                                continue;
+                            }
+                            *latest = std::cmp::max(*latest, event_time);
+                        }
+                    }
+                    session.give(result);
+                }
+            }
+        }
+    })
+}
+
+/// Returns the event time of `row`, in milliseconds since the Unix epoch, as
+/// read from its `timestamp_column`, or `None` if the column is null.
+fn event_time(row: &Row, timestamp_column: usize) -> Option<i64> {
+    match row.iter().nth(timestamp_column) {
+        Some(Datum::Timestamp(ts)) => Some(ts.timestamp_millis()),
+        Some(Datum::TimestampTz(ts)) => Some(ts.timestamp_millis()),
+        _ => None,
     }
 }
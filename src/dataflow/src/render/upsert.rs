@@ -393,36 +393,76 @@ where
                                 Some(Ok(decoded_key)) => {
                                     let decoded_value = match data.value {
                                         None => Ok(None),
-                                        Some(value) => match value {
-                                            Ok(row) => {
-                                                let envelope_value = match upsert_envelope.style {
-                                                    UpsertStyle::Debezium { after_idx } => {
-                                                        match row.iter().nth(after_idx).unwrap() {
+                                        Some(value) => {
+                                            // Holds the text of an inlined decoding error, which
+                                            // the datums borrow.
+                                            let inline_err;
+                                            let envelope_value =
+                                                match (&value, &upsert_envelope.style) {
+                                                    (
+                                                        Ok(row),
+                                                        UpsertStyle::Debezium { after_idx },
+                                                    ) => {
+                                                        match row.iter().nth(*after_idx).unwrap() {
                                                             Datum::List(after) => {
                                                                 let mut datums = Vec::with_capacity(
                                                                     source_arity,
                                                                 );
                                                                 datums.extend(after.iter());
-                                                                Some(datums)
+                                                                Ok(Some(datums))
                                                             }
-                                                            Datum::Null => None,
+                                                            Datum::Null => Ok(None),
                                                             d => panic!(
                                                                 "type error: expected record, \
-                                                                        found {:?}",
+                                                                    found {:?}",
                                                                 d
                                                             ),
                                                         }
                                                     }
-                                                    UpsertStyle::Default(_) => {
+                                                    (Ok(row), UpsertStyle::Default(_)) => {
                                                         let mut datums =
                                                             Vec::with_capacity(source_arity);
                                                         datums.extend(decoded_key.iter());
                                                         datums.extend(row.iter());
-                                                        Some(datums)
+                                                        Ok(Some(datums))
                                                     }
+                                                    (
+                                                        Ok(row),
+                                                        UpsertStyle::ValueErrInline { .. },
+                                                    ) => {
+                                                        let mut datums =
+                                                            Vec::with_capacity(source_arity);
+                                                        datums.extend(decoded_key.iter());
+                                                        datums.extend(row.iter());
+                                                        datums.push(Datum::Null);
+                                                        Ok(Some(datums))
+                                                    }
+                                                    (
+                                                        Err(DataflowError::DecodeError(err)),
+                                                        UpsertStyle::ValueErrInline { .. },
+                                                    ) => {
+                                                        // Null out the value columns, which are
+                                                        // followed by the error and metadata columns.
+                                                        let mut datums =
+                                                            Vec::with_capacity(source_arity);
+                                                        datums.extend(decoded_key.iter());
+                                                        let value_arity = source_arity
+                                                            - datums.len()
+                                                            - 1
+                                                            - data.metadata.iter().count();
+                                                        datums.extend(
+                                                            std::iter::repeat(Datum::Null)
+                                                                .take(value_arity),
+                                                        );
+                                                        inline_err = err.to_string();
+                                                        datums.push(Datum::String(&inline_err));
+                                                        Ok(Some(datums))
+                                                    }
+                                                    (Err(err), _) => Err(err.clone()),
                                                 };
 
-                                                if let Some(mut datums) = envelope_value {
+                                            match envelope_value {
+                                                Ok(Some(mut datums)) => {
                                                     datums.extend(data.metadata.iter());
                                                     evaluate(
                                                         &datums,
@@ -431,12 +471,11 @@ where
                                                         &mut row_packer,
                                                     )
                                                     .map_err(DataflowError::from)
-                                                } else {
-                                                    Ok(None)
                                                 }
+                                                Ok(None) => Ok(None),
+                                                Err(err) => Err(err),
                                             }
-                                            Err(err) => Err(err),
-                                        },
+                                        }
                                     };
                                     // Turns Ok(None) into None, and others into Some(OK) and Some(Err).
                                     // We store errors as well as non-None values, so that they can be
@@ -479,7 +479,14 @@ impl KafkaSinkState {
         if connector.exactly_once {
             // TODO(aljoscha): this only works for now, once there's an actual
             // Kafka producer on each worker they would step on each others toes
-            let transactional_id = format!("mz-producer-{}", connector.topic);
+            let transactional_id = format!(
+                "{}-{}",
+                connector
+                    .transactional_id_prefix
+                    .as_deref()
+                    .unwrap_or("mz-producer"),
+                connector.topic
+            );
             config.set("transactional.id", transactional_id);
         }
 
@@ -114,6 +114,9 @@ pub struct SourceConfig<'a, G> {
     /// Timestamp Frequency: frequency at which timestamps should be closed (and capabilities
     /// downgraded)
     pub timestamp_frequency: Duration,
+    /// The maximum number of messages this worker reads from the source per
+    /// second, if limited. Only honored by [`create_source`].
+    pub max_records_per_second: Option<u64>,
     /// Whether this worker has been chosen to actually receive data.
     pub active: bool,
     /// Data encoding
@@ -618,6 +621,55 @@ enum MessageProcessing {
     Active,
     Yielded,
     YieldedWithDelay,
+    Throttled(Duration),
+}
+
+/// Paces the messages a source reads to a fixed number per second.
+///
+/// Credit for messages that were not read while the source was idle is kept
+/// for at most a second, which bounds the burst that follows an idle period
+/// to a second's worth of messages.
+struct RateLimiter {
+    per_second: u64,
+    start: Instant,
+    admitted: u64,
+}
+
+impl RateLimiter {
+    fn new(per_second: u64) -> Self {
+        RateLimiter {
+            per_second,
+            start: Instant::now(),
+            admitted: 0,
+        }
+    }
+
+    /// Returns how long the source must wait before reading its next message,
+    /// or `None` if it may read it now.
+    fn delay(&mut self) -> Option<Duration> {
+        self.delay_at(Instant::now())
+    }
+
+    /// Like [`RateLimiter::delay`], but as of `now`.
+    fn delay_at(&mut self, now: Instant) -> Option<Duration> {
+        let due = Duration::from_secs_f64(self.admitted as f64 / self.per_second as f64);
+        let elapsed = now.duration_since(self.start);
+        let max_credit = Duration::from_secs(1);
+        if elapsed > due + max_credit {
+            // Forfeit the credit beyond the maximum.
+            self.start += elapsed - due - max_credit;
+        }
+        if due > elapsed {
+            Some(due - elapsed)
+        } else {
+            None
+        }
+    }
+
+    /// Records that the source read a message.
+    fn admit(&mut self) {
+        self.admitted += 1;
+    }
 }
 
 type EventSender =
@@ -913,6 +965,7 @@ where
         worker_id,
         worker_count,
         timestamp_frequency,
+        max_records_per_second,
         active,
         encoding,
         logger,
@@ -1117,6 +1170,8 @@ where
             }
         };
 
+        let mut rate_limiter = max_records_per_second.map(RateLimiter::new);
+
         let mut timestamp_bindings_updater = if should_emit_timestamp_bindings {
             let restored_bindings = restored_bindings.expect("missing restored bindings");
             Some(TimestampBindingUpdater::new(restored_bindings))
@@ -1182,8 +1237,15 @@ where
 
             let mut source_state = (SourceStatus::Alive, MessageProcessing::Active);
             while let (_, MessageProcessing::Active) = source_state {
+                if let Some(delay) = rate_limiter.as_mut().and_then(|l| l.delay()) {
+                    source_state = (SourceStatus::Alive, MessageProcessing::Throttled(delay));
+                    break;
+                }
                 source_state = match source_reader.get_next_message() {
                     Ok(NextMessage::Ready(message)) => {
+                        if let Some(rate_limiter) = &mut rate_limiter {
+                            rate_limiter.admit();
+                        }
                         partition_cursors.insert(message.partition.clone(), message.offset + 1);
                         handle_message::<S>(
                             message,
@@ -1247,6 +1309,7 @@ where
                 MessageProcessing::YieldedWithDelay => {
                     activator.activate_after(timestamp_frequency)
                 }
+                MessageProcessing::Throttled(delay) => activator.activate_after(delay),
                 _ => (),
             }
 
@@ -1580,3 +1643,59 @@ fn handle_message<S: SourceReader>(
         (SourceStatus::Alive, MessageProcessing::Active)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::RateLimiter;
+
+    fn limiter(per_second: u64) -> (RateLimiter, Instant) {
+        let limiter = RateLimiter::new(per_second);
+        let start = limiter.start;
+        (limiter, start)
+    }
+
+    #[test]
+    fn test_rate_limiter_pacing() {
+        let (mut limiter, start) = limiter(4);
+        assert_eq!(limiter.delay_at(start), None);
+        limiter.admit();
+        assert_eq!(limiter.delay_at(start), Some(Duration::from_millis(250)));
+        assert_eq!(
+            limiter.delay_at(start + Duration::from_millis(100)),
+            Some(Duration::from_millis(150))
+        );
+        assert_eq!(limiter.delay_at(start + Duration::from_millis(250)), None);
+        limiter.admit();
+        assert_eq!(
+            limiter.delay_at(start + Duration::from_millis(250)),
+            Some(Duration::from_millis(250))
+        );
+        // Being behind schedule by less than a second is made up for.
+        let now = start + Duration::from_millis(1250);
+        limiter.admit();
+        limiter.admit();
+        limiter.admit();
+        assert_eq!(limiter.delay_at(now), None);
+        limiter.admit();
+        limiter.admit();
+        assert_eq!(limiter.delay_at(now), Some(Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn test_rate_limiter_credit_cap() {
+        let (mut limiter, start) = limiter(4);
+        // After a long idle period, the source may only read a second's worth
+        // of messages, plus the one that is due now, before it is paced again.
+        let now = start + Duration::from_secs(10);
+        let mut burst = 0;
+        while limiter.delay_at(now).is_none() {
+            limiter.admit();
+            burst += 1;
+        }
+        assert_eq!(burst, 5);
+        assert_eq!(limiter.delay_at(now), Some(Duration::from_millis(250)));
+        assert_eq!(limiter.delay_at(now + Duration::from_millis(250)), None);
+    }
+}
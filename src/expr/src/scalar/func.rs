@@ -3240,6 +3240,7 @@ pub enum UnaryFunc {
     IsFalse(IsFalse),
     BitNotInt16(BitNotInt16),
     BitNotInt32(BitNotInt32),
+    BitCountInt32(BitCountInt32),
     BitNotInt64(BitNotInt64),
     NegInt16(NegInt16),
     NegInt32(NegInt32),
@@ -3269,6 +3270,7 @@ pub enum UnaryFunc {
     CastInt2VectorToArray(CastInt2VectorToArray),
     CastInt32ToBool(CastInt32ToBool),
     CastInt32ToFloat32(CastInt32ToFloat32),
+    TryCastInt32ToFloat32(TryCastInt32ToFloat32),
     CastInt32ToFloat64(CastInt32ToFloat64),
     CastInt32ToOid(CastInt32ToOid),
     CastInt32ToRegClass(CastInt32ToRegClass),
@@ -3276,7 +3278,9 @@ pub enum UnaryFunc {
     CastInt32ToRegType(CastInt32ToRegType),
     CastInt32ToInt16(CastInt32ToInt16),
     CastInt32ToInt64(CastInt32ToInt64),
+    CastInt32ToInt4Array(CastInt32ToInt4Array),
     CastInt32ToString(CastInt32ToString),
+    ToHexInt32(ToHexInt32),
     CastOidToInt32(CastOidToInt32),
     CastOidToInt64(CastOidToInt64),
     CastOidToRegClass(CastOidToRegClass),
@@ -3379,6 +3383,7 @@ pub enum UnaryFunc {
         cast_expr: Box<MirScalarExpr>,
     },
     CastArrayToListOneDim(CastArrayToListOneDim),
+    CastArrayToArray(CastArrayToArray),
     CastMapToString {
         ty: ScalarType,
     },
@@ -3478,6 +3483,7 @@ derive_unary!(
     AbsInt64,
     BitNotInt16,
     BitNotInt32,
+    BitCountInt32,
     BitNotInt64,
     RoundFloat32,
     RoundFloat64,
@@ -3505,10 +3511,13 @@ derive_unary!(
     CastInt2VectorToArray,
     CastInt32ToBool,
     CastInt32ToFloat32,
+    TryCastInt32ToFloat32,
     CastInt32ToFloat64,
     CastInt32ToInt16,
     CastInt32ToInt64,
+    CastInt32ToInt4Array,
     CastInt32ToString,
+    ToHexInt32,
     CastInt32ToOid,
     CastInt32ToRegClass,
     CastInt32ToRegProc,
@@ -3588,6 +3597,7 @@ derive_unary!(
     CastCharToString,
     CastUuidToString,
     CastArrayToListOneDim,
+    CastArrayToArray,
     Cos,
     Acos,
     Cosh,
@@ -3650,6 +3660,7 @@ impl UnaryFunc {
             | AbsInt64(_)
             | BitNotInt16(_)
             | BitNotInt32(_)
+            | BitCountInt32(_)
             | BitNotInt64(_)
             | RoundFloat32(_)
             | RoundFloat64(_)
@@ -3705,10 +3716,13 @@ impl UnaryFunc {
             | CastInt2VectorToArray(_)
             | CastInt32ToBool(_)
             | CastInt32ToFloat32(_)
+            | TryCastInt32ToFloat32(_)
             | CastInt32ToFloat64(_)
             | CastInt32ToInt16(_)
             | CastInt32ToInt64(_)
+            | CastInt32ToInt4Array(_)
             | CastInt32ToString(_)
+            | ToHexInt32(_)
             | CastInt32ToOid(_)
             | CastInt32ToRegClass(_)
             | CastInt32ToRegProc(_)
@@ -3781,6 +3795,7 @@ impl UnaryFunc {
             | NegInterval(_)
             | CastUuidToString(_)
             | CastArrayToListOneDim(_)
+            | CastArrayToArray(_)
             | CastTimestampToString(_)
             | CastTimestampTzToString(_)
             | CastTimestampToDate(_)
@@ -3884,6 +3899,7 @@ impl UnaryFunc {
             | AbsInt64(_)
             | BitNotInt16(_)
             | BitNotInt32(_)
+            | BitCountInt32(_)
             | BitNotInt64(_)
             | RoundFloat32(_)
             | RoundFloat64(_)
@@ -3939,10 +3955,13 @@ impl UnaryFunc {
             | CastInt2VectorToArray(_)
             | CastInt32ToBool(_)
             | CastInt32ToFloat32(_)
+            | TryCastInt32ToFloat32(_)
             | CastInt32ToFloat64(_)
             | CastInt32ToInt16(_)
             | CastInt32ToInt64(_)
+            | CastInt32ToInt4Array(_)
             | CastInt32ToString(_)
+            | ToHexInt32(_)
             | CastInt32ToOid(_)
             | CastInt32ToRegClass(_)
             | CastInt32ToRegProc(_)
@@ -4015,6 +4034,7 @@ impl UnaryFunc {
             | NegInterval(_)
             | CastUuidToString(_)
             | CastArrayToListOneDim(_)
+            | CastArrayToArray(_)
             | CastTimestampToString(_)
             | CastTimestampTzToString(_)
             | CastTimestampToDate(_)
@@ -4141,6 +4161,7 @@ impl UnaryFunc {
             | AbsInt64(_)
             | BitNotInt16(_)
             | BitNotInt32(_)
+            | BitCountInt32(_)
             | BitNotInt64(_)
             | RoundFloat32(_)
             | RoundFloat64(_)
@@ -4196,10 +4217,13 @@ impl UnaryFunc {
             | CastInt2VectorToArray(_)
             | CastInt32ToBool(_)
             | CastInt32ToFloat32(_)
+            | TryCastInt32ToFloat32(_)
             | CastInt32ToFloat64(_)
             | CastInt32ToInt16(_)
             | CastInt32ToInt64(_)
+            | CastInt32ToInt4Array(_)
             | CastInt32ToString(_)
+            | ToHexInt32(_)
             | CastInt32ToOid(_)
             | CastInt32ToRegClass(_)
             | CastInt32ToRegProc(_)
@@ -4272,6 +4296,7 @@ impl UnaryFunc {
             | NegInterval(_)
             | CastUuidToString(_)
             | CastArrayToListOneDim(_)
+            | CastArrayToArray(_)
             | CastTimestampToString(_)
             | CastTimestampTzToString(_)
             | CastTimestampToDate(_)
@@ -4418,6 +4443,7 @@ impl UnaryFunc {
             | AbsInt64(_)
             | BitNotInt16(_)
             | BitNotInt32(_)
+            | BitCountInt32(_)
             | BitNotInt64(_)
             | RoundFloat32(_)
             | RoundFloat64(_)
@@ -4473,10 +4499,13 @@ impl UnaryFunc {
             | CastInt16ToString(_)
             | CastInt32ToBool(_)
             | CastInt32ToFloat32(_)
+            | TryCastInt32ToFloat32(_)
             | CastInt32ToFloat64(_)
             | CastInt32ToInt16(_)
             | CastInt32ToInt64(_)
+            | CastInt32ToInt4Array(_)
             | CastInt32ToString(_)
+            | ToHexInt32(_)
             | CastInt32ToOid(_)
             | CastInt32ToRegClass(_)
             | CastInt32ToRegProc(_)
@@ -4549,6 +4578,7 @@ impl UnaryFunc {
             | NegInterval(_)
             | CastUuidToString(_)
             | CastArrayToListOneDim(_)
+            | CastArrayToArray(_)
             | CastTimestampToString(_)
             | CastTimestampTzToString(_)
             | CastTimestampToDate(_)
@@ -6034,6 +6064,8 @@ impl fmt::Display for VariadicFunc {
 mod test {
     use chrono::prelude::*;
 
+    use mz_repr::RelationType;
+
     use super::*;
 
     #[test]
@@ -6074,6 +6106,100 @@ mod test {
         NaiveDate::from_ymd(year, month, 1).and_hms(9, 9, 9)
     }
 
+    #[test]
+    fn cast_int32_to_int4_array() {
+        let arena = RowArena::new();
+        let expr = MirScalarExpr::literal_ok(Datum::Int32(42), ScalarType::Int32)
+            .call_unary(UnaryFunc::CastInt32ToInt4Array(CastInt32ToInt4Array));
+
+        let int4_array = ScalarType::Array(Box::new(ScalarType::Int32));
+        assert_eq!(
+            expr.typ(&RelationType::empty()),
+            int4_array.clone().nullable(false)
+        );
+
+        let array = expr.eval(&[], &arena).unwrap().unwrap_array();
+        assert_eq!(array.dims().ndims(), 1);
+        assert_eq!(
+            array.elements().iter().collect::<Vec<_>>(),
+            vec![Datum::Int32(42)]
+        );
+
+        // The result must be concatenable with other `int4[]`s.
+        let concat = expr.clone().call_binary(expr, BinaryFunc::ArrayArrayConcat);
+        assert_eq!(concat.typ(&RelationType::empty()).scalar_type, int4_array);
+        let array = concat.eval(&[], &arena).unwrap().unwrap_array();
+        assert_eq!(
+            array.elements().iter().collect::<Vec<_>>(),
+            vec![Datum::Int32(42), Datum::Int32(42)]
+        );
+    }
+
+    // Tests that integer division and modulo truncate toward zero, as in
+    // PostgreSQL, and agree across integer widths.
+    #[test]
+    fn div_mod_int_truncate_toward_zero() {
+        assert_eq!(
+            div_int32(Datum::Int32(-7), Datum::Int32(2)),
+            Ok(Datum::Int32(-3))
+        );
+        assert_eq!(
+            mod_int32(Datum::Int32(-7), Datum::Int32(2)),
+            Ok(Datum::Int32(-1))
+        );
+        assert_eq!(
+            div_int64(Datum::Int64(-7), Datum::Int64(2)),
+            Ok(Datum::Int64(-3))
+        );
+        assert_eq!(
+            mod_int64(Datum::Int64(-7), Datum::Int64(2)),
+            Ok(Datum::Int64(-1))
+        );
+
+        let values = [-1000, -37, -8, -7, -2, -1, 0, 1, 2, 7, 8, 37, 1000];
+        for a in values {
+            for b in values {
+                if b == 0 {
+                    assert_eq!(
+                        div_int32(Datum::Int32(a), Datum::Int32(b)),
+                        Err(EvalError::DivisionByZero)
+                    );
+                    assert_eq!(
+                        mod_int64(Datum::Int64(a.into()), Datum::Int64(b.into())),
+                        Err(EvalError::DivisionByZero)
+                    );
+                    continue;
+                }
+                let q32 = div_int32(Datum::Int32(a), Datum::Int32(b))
+                    .unwrap()
+                    .unwrap_int32();
+                let r32 = mod_int32(Datum::Int32(a), Datum::Int32(b))
+                    .unwrap()
+                    .unwrap_int32();
+                let q64 = div_int64(Datum::Int64(a.into()), Datum::Int64(b.into()))
+                    .unwrap()
+                    .unwrap_int64();
+                let r64 = mod_int64(Datum::Int64(a.into()), Datum::Int64(b.into()))
+                    .unwrap()
+                    .unwrap_int64();
+
+                assert_eq!(q32 * b + r32, a, "{} / {}", a, b);
+                assert_eq!(i64::from(q32), q64, "{} / {}", a, b);
+                assert_eq!(i64::from(r32), r64, "{} % {}", a, b);
+                // The remainder takes the sign of the dividend, and the
+                // quotient is never further from zero than the exact quotient.
+                assert!(r32 == 0 || (r32 < 0) == (a < 0), "{} % {}", a, b);
+                assert!(r32.abs() < b.abs(), "{} % {}", a, b);
+                assert!(
+                    i64::from(q32.abs()) * i64::from(b.abs()) <= i64::from(a.abs()),
+                    "{} / {}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
     // Tests that `UnaryFunc::output_type` are consistent with
     // `UnaryFunc::introduces_nulls` and `UnaryFunc::propagates_nulls`.
     // Currently, only unit variants of UnaryFunc are tested because those are
@@ -77,3 +77,72 @@ impl fmt::Display for CastArrayToListOneDim {
         f.write_str("arraytolist")
     }
 }
+
+#[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
+pub struct CastArrayToArray {
+    // Target array's type.
+    pub return_ty: ScalarType,
+    // The expression to cast the source array's elements to the target
+    // array's element type.
+    pub cast_expr: Box<MirScalarExpr>,
+}
+
+impl LazyUnaryFunc for CastArrayToArray {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+
+        let arr = a.unwrap_array();
+        let dims = arr.dims().into_iter().collect::<Vec<_>>();
+        let elements = arr
+            .elements()
+            .iter()
+            .enumerate()
+            .map(|(i, el)| {
+                // `cast_expr` casts the first column in `datums` from the
+                // source array's element type to the target's.
+                self.cast_expr
+                    .eval(&[el], temp_storage)
+                    .map_err(|e| EvalError::ArrayElementCast {
+                        position: i + 1,
+                        error: Box::new(e),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(temp_storage.try_make_datum(|packer| packer.push_array(&dims, elements))?)
+    }
+
+    /// The output ColumnType of this function
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        self.return_ty.clone().nullable(input_type.nullable)
+    }
+
+    /// Whether this function will produce NULL on NULL input
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    /// Whether this function will produce NULL on non-NULL input
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    /// Whether this function preserves uniqueness
+    fn preserves_uniqueness(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for CastArrayToArray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("arraytoarray")
+    }
+}
@@ -14,15 +14,15 @@ use serde::{Deserialize, Serialize};
 use mz_lowertest::MzReflect;
 use mz_repr::adt::numeric::{self, Numeric, NumericMaxScale};
 use mz_repr::adt::system::{Oid, RegClass, RegProc, RegType};
-use mz_repr::{strconv, ColumnType, ScalarType};
+use mz_repr::{strconv, ColumnType, Datum, RowArena, ScalarType};
 
-use crate::scalar::func::EagerUnaryFunc;
-use crate::EvalError;
+use crate::scalar::func::{array_create_scalar, EagerUnaryFunc, LazyUnaryFunc};
+use crate::{EvalError, MirScalarExpr};
 
 sqlfunc!(
     #[sqlname = "-"]
-    fn neg_int32(a: i32) -> i32 {
-        -a
+    fn neg_int32(a: i32) -> Result<i32, EvalError> {
+        a.checked_neg().ok_or(EvalError::Int32OutOfRange)
     }
 );
 
@@ -33,10 +33,20 @@ sqlfunc!(
     }
 );
 
+// Counts the set bits in the two's complement representation of `a`, so that
+// negative numbers count their sign bit and any bits it extends into.
+sqlfunc!(
+    #[sqlname = "bit_count"]
+    fn bit_count_int32(a: i32) -> i32 {
+        // Cannot overflow, as an `i32` has at most 32 set bits.
+        a.count_ones() as i32
+    }
+);
+
 sqlfunc!(
     #[sqlname = "abs"]
-    fn abs_int32(a: i32) -> i32 {
-        a.abs()
+    fn abs_int32(a: i32) -> Result<i32, EvalError> {
+        a.checked_abs().ok_or(EvalError::Int32OutOfRange)
     }
 );
 
@@ -47,6 +57,8 @@ sqlfunc!(
     }
 );
 
+// Rounds to the nearest `f32` if `a` is not exactly representable, which can
+// happen only when `|a| > 2^24`.
 sqlfunc!(
     #[sqlname = "i32tof32"]
     fn cast_int32_to_float32(a: i32) -> f32 {
@@ -54,6 +66,21 @@ sqlfunc!(
     }
 );
 
+// Like `cast_int32_to_float32`, but errors rather than rounds when `a` is not
+// exactly representable as an `f32`. Every `a` with `|a| <= 2^24` is exactly
+// representable; beyond that, only multiples of increasing powers of two are.
+sqlfunc!(
+    #[sqlname = "i32tof32exact"]
+    fn try_cast_int32_to_float32(a: i32) -> Result<f32, EvalError> {
+        let f = a as f32;
+        if f64::from(f) == f64::from(a) {
+            Ok(f)
+        } else {
+            Err(EvalError::Float32NotExact(a))
+        }
+    }
+);
+
 sqlfunc!(
     #[sqlname = "i32tof64"]
     #[preserves_uniqueness = true]
@@ -66,7 +93,7 @@ sqlfunc!(
     #[sqlname = "i32toi16"]
     #[preserves_uniqueness = true]
     fn cast_int32_to_int16(a: i32) -> Result<i16, EvalError> {
-        i16::try_from(a).or(Err(EvalError::Int16OutOfRange))
+        i16::try_from(a).or(Err(EvalError::Int16OutOfRangeValue(a)))
     }
 );
 
@@ -78,6 +105,51 @@ sqlfunc!(
     }
 );
 
+/// Wraps an `int4` into a one-dimensional, single-element `int4[]`.
+#[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
+pub struct CastInt32ToInt4Array;
+
+impl LazyUnaryFunc for CastInt32ToInt4Array {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        array_create_scalar(&[a], temp_storage)
+    }
+
+    /// The output ColumnType of this function
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        ScalarType::Array(Box::new(ScalarType::Int32)).nullable(input_type.nullable)
+    }
+
+    /// Whether this function will produce NULL on NULL input
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    /// Whether this function will produce NULL on non-NULL input
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    /// Whether this function preserves uniqueness
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+}
+
+impl fmt::Display for CastInt32ToInt4Array {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("i32toi32array")
+    }
+}
+
 sqlfunc!(
     #[sqlname = "i32tostr"]
     #[preserves_uniqueness = true]
@@ -88,6 +160,16 @@ sqlfunc!(
     }
 );
 
+sqlfunc!(
+    #[sqlname = "to_hex"]
+    #[preserves_uniqueness = true]
+    fn to_hex_int32(a: i32) -> String {
+        // Like PostgreSQL, format the bits of negative values as if they were
+        // unsigned.
+        format!("{:x}", a as u32)
+    }
+);
+
 #[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
 pub struct CastInt32ToNumeric(pub Option<NumericMaxScale>);
 
@@ -96,10 +178,15 @@ impl<'a> EagerUnaryFunc<'a> for CastInt32ToNumeric {
     type Output = Result<Numeric, EvalError>;
 
     fn call(&self, a: i32) -> Result<Numeric, EvalError> {
+        let value = a;
         let mut a = Numeric::from(a);
         if let Some(scale) = self.0 {
+            // Every `i32` fits in a numeric, so only the scale can overflow.
             if numeric::rescale(&mut a, scale.into_u8()).is_err() {
-                return Err(EvalError::NumericFieldOverflow);
+                return Err(EvalError::NumericScaleOverflow {
+                    value: value.to_string(),
+                    scale: scale.into_u8(),
+                });
             }
         }
         // Besides `rescale`, cast is infallible.
@@ -125,27 +212,36 @@ sqlfunc!(
     }
 );
 
+/// Rejects values that cannot name a catalog object, as OIDs are unsigned.
+fn validate_oid(a: i32) -> Result<i32, EvalError> {
+    if a < 0 {
+        Err(EvalError::NegativeOid(a))
+    } else {
+        Ok(a)
+    }
+}
+
 sqlfunc!(
     #[sqlname = "i32toregclass"]
     #[preserves_uniqueness = true]
-    fn cast_int32_to_reg_class(a: i32) -> RegClass {
-        RegClass(a)
+    fn cast_int32_to_reg_class(a: i32) -> Result<RegClass, EvalError> {
+        validate_oid(a).map(RegClass)
     }
 );
 
 sqlfunc!(
     #[sqlname = "i32toregproc"]
     #[preserves_uniqueness = true]
-    fn cast_int32_to_reg_proc(a: i32) -> RegProc {
-        RegProc(a)
+    fn cast_int32_to_reg_proc(a: i32) -> Result<RegProc, EvalError> {
+        validate_oid(a).map(RegProc)
     }
 );
 
 sqlfunc!(
     #[sqlname = "i32toregtype"]
     #[preserves_uniqueness = true]
-    fn cast_int32_to_reg_type(a: i32) -> RegType {
-        RegType(a)
+    fn cast_int32_to_reg_type(a: i32) -> Result<RegType, EvalError> {
+        validate_oid(a).map(RegType)
     }
 );
 
@@ -166,3 +262,74 @@ sqlfunc!(
         }
     }
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neg_overflow() {
+        assert_eq!(neg_int32(i32::MIN), Err(EvalError::Int32OutOfRange));
+        assert_eq!(neg_int32(i32::MAX), Ok(-i32::MAX));
+        assert_eq!(neg_int32(0), Ok(0));
+    }
+
+    #[test]
+    fn abs_overflow() {
+        assert_eq!(abs_int32(i32::MIN), Err(EvalError::Int32OutOfRange));
+        assert_eq!(abs_int32(i32::MIN + 1), Ok(i32::MAX));
+        assert_eq!(abs_int32(i32::MAX), Ok(i32::MAX));
+    }
+
+    #[test]
+    fn cast_to_int16_overflow() {
+        assert_eq!(cast_int32_to_int16(i32::from(i16::MAX)), Ok(i16::MAX));
+        assert_eq!(cast_int32_to_int16(i32::from(i16::MIN)), Ok(i16::MIN));
+        assert_eq!(
+            cast_int32_to_int16(i32::from(i16::MAX) + 1),
+            Err(EvalError::Int16OutOfRangeValue(32768))
+        );
+        assert_eq!(
+            cast_int32_to_int16(i32::from(i16::MIN) - 1),
+            Err(EvalError::Int16OutOfRangeValue(-32769))
+        );
+    }
+
+    #[test]
+    fn cast_to_float32_precision() {
+        let exact = 1 << 24;
+        assert_eq!(cast_int32_to_float32(exact), 16777216.0);
+        assert_eq!(try_cast_int32_to_float32(exact), Ok(16777216.0));
+        // 2^24 + 1 is the smallest positive integer that is not exactly
+        // representable as an `f32`; it rounds to 2^24.
+        assert_eq!(cast_int32_to_float32(exact + 1), 16777216.0);
+        assert_eq!(
+            try_cast_int32_to_float32(exact + 1),
+            Err(EvalError::Float32NotExact(exact + 1))
+        );
+        assert_eq!(
+            try_cast_int32_to_float32(-exact - 1),
+            Err(EvalError::Float32NotExact(-exact - 1))
+        );
+    }
+
+    #[test]
+    fn chr_invalid_codepoints() {
+        assert_eq!(chr(0), Err(EvalError::NullCharacterNotPermitted));
+        assert_eq!(
+            chr(0xd800),
+            Err(EvalError::CharacterNotValidForEncoding(0xd800))
+        );
+        assert_eq!(
+            chr(0xdfff),
+            Err(EvalError::CharacterNotValidForEncoding(0xdfff))
+        );
+        assert_eq!(chr(0xd7ff), Ok("\u{d7ff}".to_string()));
+        assert_eq!(chr(0xe000), Ok("\u{e000}".to_string()));
+        assert_eq!(chr(-1), Err(EvalError::CharacterTooLargeForEncoding(-1)));
+        assert_eq!(
+            chr(0x110000),
+            Err(EvalError::CharacterTooLargeForEncoding(0x110000))
+        );
+    }
+}
@@ -1229,12 +1229,19 @@ pub enum EvalError {
     FloatOverflow,
     FloatUnderflow,
     NumericFieldOverflow,
+    NumericScaleOverflow {
+        value: String,
+        scale: u8,
+    },
     Float32OutOfRange,
+    Float32NotExact(i32),
     Float64OutOfRange,
     Int16OutOfRange,
+    Int16OutOfRangeValue(i32),
     Int32OutOfRange,
     Int64OutOfRange,
     OidOutOfRange,
+    NegativeOid(i32),
     IntervalOutOfRange,
     TimestampOutOfRange,
     InvalidBase64Equals,
@@ -1286,6 +1293,11 @@ pub enum EvalError {
     IncompatibleArrayDimensions {
         dims: Option<(usize, usize)>,
     },
+    ArrayElementCast {
+        /// The 1-based position of the element in the array's elements.
+        position: usize,
+        error: Box<EvalError>,
+    },
 }
 
 impl fmt::Display for EvalError {
@@ -1309,12 +1321,25 @@ impl fmt::Display for EvalError {
             EvalError::FloatOverflow => f.write_str("value out of range: overflow"),
             EvalError::FloatUnderflow => f.write_str("value out of range: underflow"),
             EvalError::NumericFieldOverflow => f.write_str("numeric field overflow"),
+            EvalError::NumericScaleOverflow { value, scale } => write!(
+                f,
+                "numeric field overflow: value {value} does not fit with scale {scale}"
+            ),
             EvalError::Float32OutOfRange => f.write_str("real out of range"),
+            EvalError::Float32NotExact(v) => {
+                write!(f, "value {v} cannot be represented exactly as type real")
+            }
             EvalError::Float64OutOfRange => f.write_str("double precision out of range"),
             EvalError::Int16OutOfRange => f.write_str("smallint out of range"),
+            EvalError::Int16OutOfRangeValue(v) => {
+                write!(f, "value {v} is out of range for type smallint")
+            }
             EvalError::Int32OutOfRange => f.write_str("integer out of range"),
             EvalError::Int64OutOfRange => f.write_str("bigint out of range"),
             EvalError::OidOutOfRange => f.write_str("OID out of range"),
+            EvalError::NegativeOid(oid) => {
+                write!(f, "invalid OID {}: OIDs cannot be negative", oid)
+            }
             EvalError::IntervalOutOfRange => f.write_str("interval out of range"),
             EvalError::TimestampOutOfRange => f.write_str("timestamp out of range"),
             EvalError::InvalidBase64Equals => {
@@ -1423,6 +1448,9 @@ impl fmt::Display for EvalError {
             EvalError::IncompatibleArrayDimensions { dims: _ } => {
                 write!(f, "cannot concatenate incompatible arrays")
             }
+            EvalError::ArrayElementCast { position, error } => {
+                write!(f, "{} (array element {})", error, position)
+            }
         }
     }
 }
@@ -1440,6 +1468,12 @@ impl EvalError {
                 "Arrays of {} and {} dimensions are not compatible for concatenation.",
                 a_dims, b_dims
             )),
+            EvalError::NumericScaleOverflow { .. } => Some(
+                "The value itself fits in a numeric, but not with the requested number of \
+                 digits after the decimal point."
+                    .to_string(),
+            ),
+            EvalError::ArrayElementCast { error, .. } => error.detail(),
             _ => None,
         }
     }
@@ -1452,6 +1486,7 @@ impl EvalError {
             EvalError::LikeEscapeTooLong => {
                 Some("Escape string must be empty or one character.".into())
             }
+            EvalError::ArrayElementCast { error, .. } => error.hint(),
             _ => None,
         }
     }
@@ -12,7 +12,7 @@ use criterion::{black_box, Criterion, Throughput};
 use futures::executor::block_on;
 use mz_avro::types::Value as AvroValue;
 
-use mz_interchange::avro::{parse_schema, Decoder};
+use mz_interchange::avro::{parse_schema, ConfluentWireFormat, Decoder};
 use std::ops::Add;
 
 pub fn bench_avro(c: &mut Criterion) {
@@ -389,7 +389,13 @@ pub fn bench_avro(c: &mut Criterion) {
     buf.extend(mz_avro::to_avro_datum(&schema, record).unwrap());
     let len = buf.len() as u64;
 
-    let mut decoder = Decoder::new(schema_str, None, "avro_bench".to_string(), false).unwrap();
+    let mut decoder = Decoder::new(
+        schema_str,
+        None,
+        "avro_bench".to_string(),
+        ConfluentWireFormat::Disabled,
+    )
+    .unwrap();
 
     let mut bg = c.benchmark_group("avro");
     bg.throughput(Throughput::Bytes(len));
@@ -28,7 +28,7 @@ use mz_repr::adt::jsonb::JsonbPacker;
 use mz_repr::adt::numeric;
 use mz_repr::{Datum, Row};
 
-use crate::avro::ConfluentAvroResolver;
+use crate::avro::{ConfluentAvroResolver, ConfluentWireFormat};
 
 /// Manages decoding of Avro-encoded bytes.
 #[derive(Debug)]
@@ -43,7 +43,7 @@ pub struct Decoder {
 mod tests {
     use futures::executor::block_on;
 
-    use crate::avro::Decoder;
+    use crate::avro::{ConfluentWireFormat, Decoder};
     use mz_repr::{Datum, Row};
 
     #[test]
@@ -53,7 +53,13 @@ mod tests {
 "name": "test",
 "fields": [{"name": "f1", "type": "int"}, {"name": "f2", "type": "int"}]
 }"#;
-        let mut decoder = Decoder::new(&schema, None, "Test".to_string(), false).unwrap();
+        let mut decoder = Decoder::new(
+            &schema,
+            None,
+            "Test".to_string(),
+            ConfluentWireFormat::Disabled,
+        )
+        .unwrap();
         // This is not a valid Avro blob for the given schema
         let mut bad_bytes: &[u8] = &[0];
         assert!(block_on(decoder.decode(&mut bad_bytes)).is_err());
@@ -78,7 +84,7 @@ impl Decoder {
         reader_schema: &str,
         schema_registry: Option<mz_ccsr::ClientConfig>,
         debug_name: String,
-        confluent_wire_format: bool,
+        confluent_wire_format: ConfluentWireFormat,
     ) -> anyhow::Result<Decoder> {
         let csr_avro =
             ConfluentAvroResolver::new(reader_schema, schema_registry, confluent_wire_format)?;
@@ -93,30 +99,35 @@ impl Decoder {
 
     /// Decodes Avro-encoded `bytes` into a `Row`.
     pub async fn decode(&mut self, bytes: &mut &[u8]) -> anyhow::Result<Row> {
-        // Clear out any bytes that might be left over from
-        // an earlier run. This can happen if the
-        // `dsr.deserialize` call returns an error,
-        // causing us to return early.
-        self.packer.clear();
-        let (bytes2, resolved_schema, csr_schema_id) = self.csr_avro.resolve(bytes).await?;
-        *bytes = bytes2;
-        let dec = AvroFlatDecoder {
-            packer: &mut self.packer,
-            buf: &mut self.buf1,
-            is_top: true,
-        };
-        let dsr = GeneralDeserializer {
-            schema: resolved_schema.top_node(),
-        };
-        dsr.deserialize(bytes, dec).with_context(|| {
-            format!(
-                "unable to decode row {}",
-                match csr_schema_id {
-                    Some(id) => format!("(Avro schema id = {:?})", id),
-                    None => "".to_string(),
-                }
-            )
-        })?;
+        let packer = &mut self.packer;
+        let buf = &mut self.buf1;
+        self.csr_avro
+            .resolve_and_decode(bytes, |bytes, resolved_schema, csr_schema_id| {
+                // Clear out any bytes that might be left over from
+                // an earlier run. This can happen if the
+                // `dsr.deserialize` call returns an error,
+                // causing us to return early.
+                packer.clear();
+                let dec = AvroFlatDecoder {
+                    packer: &mut *packer,
+                    buf: &mut *buf,
+                    is_top: true,
+                };
+                let dsr = GeneralDeserializer {
+                    schema: resolved_schema.top_node(),
+                };
+                dsr.deserialize(bytes, dec).with_context(|| {
+                    format!(
+                        "unable to decode row {}",
+                        match csr_schema_id {
+                            Some(id) => format!("(Avro schema id = {:?})", id),
+                            None => "".to_string(),
+                        }
+                    )
+                })?;
+                Ok(())
+            })
+            .await?;
         let result = self.packer.finish_and_reuse();
         trace!(
             "[customer-data] Decoded row {:?} in {}",
@@ -14,6 +14,7 @@ use std::str::FromStr;
 use std::time::Duration;
 
 use anyhow::{anyhow, bail, Context};
+use serde::{Deserialize, Serialize};
 use tracing::warn;
 
 use mz_avro::error::Error as AvroError;
@@ -229,17 +230,42 @@ fn validate_schema_2(
     })
 }
 
+/// Whether Avro messages are framed with the Confluent wire format header,
+/// i.e., a magic byte followed by a four byte schema ID.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ConfluentWireFormat {
+    /// Every message starts with the header.
+    Enabled,
+    /// No message starts with the header.
+    Disabled,
+    /// Each message is checked for the magic byte, and the header is skipped
+    /// if it is present. This supports topics with a mix of framings. As an
+    /// unframed message may also start with a zero byte, a message that does
+    /// not decode as framed is decoded again as unframed.
+    Auto,
+}
+
+impl From<bool> for ConfluentWireFormat {
+    fn from(enabled: bool) -> Self {
+        if enabled {
+            ConfluentWireFormat::Enabled
+        } else {
+            ConfluentWireFormat::Disabled
+        }
+    }
+}
+
 pub struct ConfluentAvroResolver {
     reader_schema: Schema,
     writer_schemas: Option<SchemaCache>,
-    confluent_wire_format: bool,
+    confluent_wire_format: ConfluentWireFormat,
 }
 
 impl ConfluentAvroResolver {
     pub fn new(
         reader_schema: &str,
         config: Option<mz_ccsr::ClientConfig>,
-        confluent_wire_format: bool,
+        confluent_wire_format: ConfluentWireFormat,
     ) -> anyhow::Result<Self> {
         let reader_schema = parse_schema(reader_schema)?;
         let writer_schemas = config.map(SchemaCache::new).transpose()?;
@@ -256,8 +282,9 @@ impl ConfluentAvroResolver {
     ) -> anyhow::Result<(&'b [u8], &'a Schema, Option<i32>)> {
         let (resolved_schema, schema_id) = match &mut self.writer_schemas {
             Some(cache) => {
-                debug_assert!(
+                debug_assert_eq!(
                     self.confluent_wire_format,
+                    ConfluentWireFormat::Enabled,
                     "We should have set 'confluent_wire_format' everywhere \
                      that can lead to this branch"
                 );
@@ -277,7 +304,12 @@ impl ConfluentAvroResolver {
             // to discover the writer's schema. That's ok; we'll just use the
             // reader's schema and hope it lines up.
             None => {
-                if self.confluent_wire_format {
+                let has_header = match self.confluent_wire_format {
+                    ConfluentWireFormat::Enabled => true,
+                    ConfluentWireFormat::Disabled => false,
+                    ConfluentWireFormat::Auto => bytes.len() >= 5 && bytes[0] == 0,
+                };
+                if has_header {
                     // validate and just move the bytes buffer ahead
                     let (_, adjusted_bytes) = crate::confluent::extract_avro_header(bytes)?;
                     bytes = adjusted_bytes;
@@ -287,6 +319,41 @@ impl ConfluentAvroResolver {
         };
         Ok((bytes, resolved_schema, schema_id))
     }
+
+    /// Resolves the schema of the Avro-encoded `bytes`, like
+    /// [`ConfluentAvroResolver::resolve`], and decodes them with `decode`,
+    /// which is passed the bytes past any header, the schema to decode them
+    /// with and the ID of that schema in the registry, if any.
+    ///
+    /// Under [`ConfluentWireFormat::Auto`], a message that starts with the
+    /// magic byte is either framed or an unframed record whose first byte is
+    /// zero. It is decoded as framed first, and decoded again as unframed if
+    /// that fails or leaves bytes unread.
+    pub async fn resolve_and_decode<'b, T, F>(
+        &mut self,
+        bytes: &mut &'b [u8],
+        mut decode: F,
+    ) -> anyhow::Result<T>
+    where
+        F: FnMut(&mut &'b [u8], &Schema, Option<i32>) -> anyhow::Result<T>,
+    {
+        if self.confluent_wire_format == ConfluentWireFormat::Auto && self.writer_schemas.is_none()
+        {
+            if let Ok((_, mut framed)) = crate::confluent::extract_avro_header(*bytes) {
+                if let Ok(value) = decode(&mut framed, &self.reader_schema, None) {
+                    if framed.is_empty() {
+                        *bytes = framed;
+                        return Ok(value);
+                    }
+                }
+                return decode(bytes, &self.reader_schema, None);
+            }
+        }
+        let (mut data, schema, schema_id) = self.resolve(*bytes).await?;
+        let value = decode(&mut data, schema, schema_id)?;
+        *bytes = data;
+        Ok(value)
+    }
 }
 
 impl fmt::Debug for ConfluentAvroResolver {
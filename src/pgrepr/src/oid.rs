@@ -96,3 +96,5 @@ pub const FUNC_MZ_DATE_BIN_HOPPING_UNIX_EPOCH_TSTZ_OID: u32 = 16_450;
 pub const FUNC_MZ_DATE_BIN_HOPPING_TS_OID: u32 = 16_451;
 pub const FUNC_MZ_DATE_BIN_HOPPING_TSTZ_OID: u32 = 16_452;
 pub const FUNC_MZ_TYPE_NAME: u32 = 16_453;
+pub const FUNC_FLOAT4_EXACT_OID: u32 = 16_454;
+pub const FUNC_BIT_COUNT_INT32_OID: u32 = 16_455;
@@ -1027,6 +1027,11 @@ where
             }};
         }
 
+        for notice in self.coord_client.session().drain_notices() {
+            let msg = ErrorResponse::notice(SqlState::WARNING, notice.to_string());
+            self.send(msg).await?;
+        }
+
         match response {
             ExecuteResponse::Canceled => {
                 return self
@@ -1040,6 +1045,9 @@ where
                 self.complete_portal(&portal_name);
                 command_complete!("CLOSE CURSOR")
             }
+            ExecuteResponse::CreatedConnection { existed } => {
+                created!(existed, SqlState::DUPLICATE_OBJECT, "connection")
+            }
             ExecuteResponse::CreatedDatabase { existed } => {
                 created!(existed, SqlState::DUPLICATE_DATABASE, "database")
             }
@@ -1074,6 +1082,7 @@ where
             ExecuteResponse::Deleted(n) => command_complete!("DELETE {}", n),
             ExecuteResponse::DiscardedTemp => command_complete!("DISCARD TEMP"),
             ExecuteResponse::DiscardedAll => command_complete!("DISCARD ALL"),
+            ExecuteResponse::DroppedConnection => command_complete!("DROP CONNECTION"),
             ExecuteResponse::DroppedDatabase => command_complete!("DROP DATABASE"),
             ExecuteResponse::DroppedSchema => command_complete!("DROP SCHEMA"),
             ExecuteResponse::DroppedRole => command_complete!("DROP ROLE"),
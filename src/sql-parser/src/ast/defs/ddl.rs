@@ -123,6 +123,9 @@ impl_display_t!(ProtobufSchema);
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CsrConnectorAvro<T: AstInfo> {
     pub url: String,
+    /// A schema to decode values into, in place of the value schema that
+    /// the registry provides.
+    pub reader_schema: Option<String>,
     pub seed: Option<CsrSeed>,
     pub with_options: Vec<SqlOption<T>>,
 }
@@ -132,6 +135,11 @@ impl<T: AstInfo> AstDisplay for CsrConnectorAvro<T> {
         f.write_str("USING CONFLUENT SCHEMA REGISTRY '");
         f.write_node(&display::escape_single_quote_string(&self.url));
         f.write_str("'");
+        if let Some(reader_schema) = &self.reader_schema {
+            f.write_str(" READER SCHEMA '");
+            f.write_node(&display::escape_single_quote_string(reader_schema));
+            f.write_str("'");
+        }
         if let Some(seed) = &self.seed {
             f.write_str(" ");
             f.write_node(seed);
@@ -285,6 +293,7 @@ pub enum Format<T: AstInfo> {
     Csv {
         columns: CsvColumns,
         delimiter: char,
+        with_options: Vec<WithOption>,
     },
     Json,
     Text,
@@ -296,6 +305,9 @@ pub enum CsvColumns {
     Count(usize),
     /// `WITH HEADER (ident, ...)?`: `names` is empty if there are no names specified
     Header { names: Vec<Ident> },
+    /// No column specification, as with `WITH (infer_columns = true)`, where
+    /// the column count is inferred from the first row
+    Infer,
 }
 
 impl AstDisplay for CsvColumns {
@@ -313,6 +325,7 @@ impl AstDisplay for CsvColumns {
                     f.write_str(")");
                 }
             }
+            CsvColumns::Infer => (),
         }
     }
 }
@@ -343,6 +356,7 @@ impl_display!(SourceIncludeMetadataType);
 pub struct SourceIncludeMetadata {
     pub ty: SourceIncludeMetadataType,
     pub alias: Option<Ident>,
+    pub options: Vec<WithOption>,
 }
 
 impl AstDisplay for SourceIncludeMetadata {
@@ -352,6 +366,11 @@ impl AstDisplay for SourceIncludeMetadata {
             f.write_str(" AS ");
             f.write_node(alias);
         }
+        if !self.options.is_empty() {
+            f.write_str(" (");
+            f.write_node(&display::comma_separated(&self.options));
+            f.write_str(")");
+        }
     }
 }
 impl_display!(SourceIncludeMetadata);
@@ -360,7 +379,7 @@ impl_display!(SourceIncludeMetadata);
 pub enum Envelope {
     None,
     Debezium(DbzMode),
-    Upsert,
+    Upsert(UpsertValueDecodingErrors),
     CdcV2,
 }
 
@@ -375,8 +394,9 @@ impl AstDisplay for Envelope {
                 f.write_str("DEBEZIUM");
                 f.write_node(mode);
             }
-            Self::Upsert => {
+            Self::Upsert(value_decoding_errors) => {
                 f.write_str("UPSERT");
+                f.write_node(value_decoding_errors);
             }
             Self::CdcV2 => {
                 f.write_str("MATERIALIZE");
@@ -403,15 +423,27 @@ impl<T: AstInfo> AstDisplay for Format<T> {
                 f.write_node(&display::escape_single_quote_string(regex));
                 f.write_str("'");
             }
-            Self::Csv { columns, delimiter } => {
-                f.write_str("CSV WITH ");
-                f.write_node(columns);
+            Self::Csv {
+                columns,
+                delimiter,
+                with_options,
+            } => {
+                f.write_str("CSV");
+                if *columns != CsvColumns::Infer {
+                    f.write_str(" WITH ");
+                    f.write_node(columns);
+                }
 
                 if *delimiter != ',' {
                     f.write_str(" DELIMITED BY '");
                     f.write_node(&display::escape_single_quote_string(&delimiter.to_string()));
                     f.write_str("'");
                 }
+                if !with_options.is_empty() {
+                    f.write_str(" WITH (");
+                    f.write_node(&display::comma_separated(with_options));
+                    f.write_str(")");
+                }
             }
             Self::Json => f.write_str("JSON"),
             Self::Text => f.write_str("TEXT"),
@@ -454,6 +486,70 @@ impl AstDisplay for DbzMode {
 }
 impl_display!(DbzMode);
 
+/// How `ENVELOPE UPSERT` handles values that fail to decode.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum UpsertValueDecodingErrors {
+    /// `ENVELOPE UPSERT` with no suffix: the error fails the source.
+    Fail,
+    /// `ENVELOPE UPSERT (VALUE DECODING ERRORS = INLINE)`: the error is
+    /// reported in a column of the key's row.
+    Inline,
+}
+
+impl AstDisplay for UpsertValueDecodingErrors {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            Self::Fail => f.write_str(""),
+            Self::Inline => f.write_str(" (VALUE DECODING ERRORS = INLINE)"),
+        }
+    }
+}
+impl_display!(UpsertValueDecodingErrors);
+
+/// How a Kafka source locates its brokers.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum KafkaConnection {
+    /// `BROKER '<addrs>'`
+    Inline { broker: String },
+    /// `USING CONNECTION <name>`
+    Reference { connection: UnresolvedObjectName },
+}
+
+impl AstDisplay for KafkaConnection {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            KafkaConnection::Inline { broker } => {
+                f.write_str("BROKER '");
+                f.write_node(&display::escape_single_quote_string(broker));
+                f.write_str("'");
+            }
+            KafkaConnection::Reference { connection } => {
+                f.write_str("USING CONNECTION ");
+                f.write_node(connection);
+            }
+        }
+    }
+}
+impl_display!(KafkaConnection);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CreateConnectionConnector {
+    Kafka { broker: String },
+}
+
+impl AstDisplay for CreateConnectionConnector {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            CreateConnectionConnector::Kafka { broker } => {
+                f.write_str("KAFKA BROKER '");
+                f.write_node(&display::escape_single_quote_string(broker));
+                f.write_str("'");
+            }
+        }
+    }
+}
+impl_display!(CreateConnectionConnector);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, EnumKind)]
 #[enum_kind(ConnectorType)]
 pub enum CreateSourceConnector {
@@ -462,7 +558,7 @@ pub enum CreateSourceConnector {
         compression: Compression,
     },
     Kafka {
-        broker: String,
+        connection: KafkaConnection,
         topic: String,
         key: Option<Vec<Ident>>,
     },
@@ -506,10 +602,13 @@ impl AstDisplay for CreateSourceConnector {
                 f.write_str(" COMPRESSION ");
                 f.write_node(compression);
             }
-            CreateSourceConnector::Kafka { broker, topic, key } => {
-                f.write_str("KAFKA BROKER '");
-                f.write_node(&display::escape_single_quote_string(broker));
-                f.write_str("'");
+            CreateSourceConnector::Kafka {
+                connection,
+                topic,
+                key,
+            } => {
+                f.write_str("KAFKA ");
+                f.write_node(connection);
                 f.write_str(" TOPIC '");
                 f.write_node(&display::escape_single_quote_string(topic));
                 f.write_str("'");
@@ -22,9 +22,9 @@ use std::fmt;
 
 use crate::ast::display::{self, AstDisplay, AstFormatter};
 use crate::ast::{
-    AstInfo, ColumnDef, CreateSinkConnector, CreateSourceConnector, CreateSourceFormat, Envelope,
-    Expr, Format, Ident, KeyConstraint, Query, SourceIncludeMetadata, TableAlias, TableConstraint,
-    TableWithJoins, UnresolvedObjectName, Value,
+    AstInfo, ColumnDef, CreateConnectionConnector, CreateSinkConnector, CreateSourceConnector,
+    CreateSourceFormat, Envelope, Expr, Format, Ident, KeyConstraint, Query, SourceIncludeMetadata,
+    TableAlias, TableConstraint, TableWithJoins, UnresolvedObjectName, Value,
 };
 
 /// A top-level statement (SELECT, INSERT, CREATE, etc.)
@@ -38,6 +38,7 @@ pub enum Statement<T: AstInfo> {
     Delete(DeleteStatement<T>),
     CreateDatabase(CreateDatabaseStatement),
     CreateSchema(CreateSchemaStatement),
+    CreateConnection(CreateConnectionStatement<T>),
     CreateSource(CreateSourceStatement<T>),
     CreateSink(CreateSinkStatement<T>),
     CreateView(CreateViewStatement<T>),
@@ -48,6 +49,8 @@ pub enum Statement<T: AstInfo> {
     CreateRole(CreateRoleStatement),
     AlterObjectRename(AlterObjectRenameStatement),
     AlterIndex(AlterIndexStatement),
+    AlterSource(AlterSourceStatement),
+    AlterView(AlterViewStatement),
     Discard(DiscardStatement),
     DropDatabase(DropDatabaseStatement),
     DropObjects(DropObjectsStatement),
@@ -68,6 +71,8 @@ pub enum Statement<T: AstInfo> {
     Rollback(RollbackStatement),
     Tail(TailStatement<T>),
     Explain(ExplainStatement<T>),
+    ExplainEncoding(ExplainEncodingStatement<T>),
+    DescribeCreateSink(DescribeCreateSinkStatement<T>),
     Declare(DeclareStatement<T>),
     Fetch(FetchStatement),
     Close(CloseStatement),
@@ -86,6 +91,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Delete(stmt) => f.write_node(stmt),
             Statement::CreateDatabase(stmt) => f.write_node(stmt),
             Statement::CreateSchema(stmt) => f.write_node(stmt),
+            Statement::CreateConnection(stmt) => f.write_node(stmt),
             Statement::CreateSource(stmt) => f.write_node(stmt),
             Statement::CreateSink(stmt) => f.write_node(stmt),
             Statement::CreateView(stmt) => f.write_node(stmt),
@@ -96,6 +102,8 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::CreateType(stmt) => f.write_node(stmt),
             Statement::AlterObjectRename(stmt) => f.write_node(stmt),
             Statement::AlterIndex(stmt) => f.write_node(stmt),
+            Statement::AlterSource(stmt) => f.write_node(stmt),
+            Statement::AlterView(stmt) => f.write_node(stmt),
             Statement::Discard(stmt) => f.write_node(stmt),
             Statement::DropDatabase(stmt) => f.write_node(stmt),
             Statement::DropObjects(stmt) => f.write_node(stmt),
@@ -116,6 +124,8 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Rollback(stmt) => f.write_node(stmt),
             Statement::Tail(stmt) => f.write_node(stmt),
             Statement::Explain(stmt) => f.write_node(stmt),
+            Statement::ExplainEncoding(stmt) => f.write_node(stmt),
+            Statement::DescribeCreateSink(stmt) => f.write_node(stmt),
             Statement::Declare(stmt) => f.write_node(stmt),
             Statement::Close(stmt) => f.write_node(stmt),
             Statement::Fetch(stmt) => f.write_node(stmt),
@@ -358,6 +368,33 @@ impl AstDisplay for CreateSchemaStatement {
 }
 impl_display!(CreateSchemaStatement);
 
+/// `CREATE CONNECTION`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateConnectionStatement<T: AstInfo> {
+    pub name: UnresolvedObjectName,
+    pub connector: CreateConnectionConnector,
+    pub with_options: Vec<SqlOption<T>>,
+    pub if_not_exists: bool,
+}
+
+impl<T: AstInfo> AstDisplay for CreateConnectionStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE CONNECTION ");
+        if self.if_not_exists {
+            f.write_str("IF NOT EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" FOR ");
+        f.write_node(&self.connector);
+        if !self.with_options.is_empty() {
+            f.write_str(" WITH (");
+            f.write_node(&display::comma_separated(&self.with_options));
+            f.write_str(")");
+        }
+    }
+}
+impl_display_t!(CreateConnectionStatement);
+
 /// `CREATE SOURCE`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateSourceStatement<T: AstInfo> {
@@ -479,6 +516,9 @@ pub struct ViewDefinition<T: AstInfo> {
     /// View name
     pub name: UnresolvedObjectName,
     pub columns: Vec<Ident>,
+    /// The declared types of the columns. If non-empty, contains one entry
+    /// per column in `columns`.
+    pub column_types: Vec<Option<T::DataType>>,
     pub with_options: Vec<SqlOption<T>>,
     pub query: Query<T>,
 }
@@ -495,7 +535,16 @@ impl<T: AstInfo> AstDisplay for ViewDefinition<T> {
 
         if !self.columns.is_empty() {
             f.write_str(" (");
-            f.write_node(&display::comma_separated(&self.columns));
+            for (i, column) in self.columns.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ");
+                }
+                f.write_node(column);
+                if let Some(Some(data_type)) = self.column_types.get(i) {
+                    f.write_str(" ");
+                    f.write_node(data_type);
+                }
+            }
             f.write_str(")");
         }
 
@@ -795,6 +844,7 @@ impl_display_t!(CreateTypeStatement);
 pub enum CreateTypeAs {
     List,
     Map,
+    Range,
 }
 
 impl AstDisplay for CreateTypeAs {
@@ -802,6 +852,7 @@ impl AstDisplay for CreateTypeAs {
         match self {
             CreateTypeAs::List => f.write_str("LIST "),
             CreateTypeAs::Map => f.write_str("MAP "),
+            CreateTypeAs::Range => f.write_str("RANGE "),
         }
     }
 }
@@ -873,6 +924,87 @@ impl AstDisplay for AlterIndexStatement {
 
 impl_display!(AlterIndexStatement);
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AlterSourceAction {
+    RenameColumn {
+        column: Ident,
+        to_column_name: Ident,
+    },
+}
+
+/// `ALTER SOURCE ... RENAME COLUMN ... TO ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterSourceStatement {
+    pub source_name: UnresolvedObjectName,
+    pub if_exists: bool,
+    pub action: AlterSourceAction,
+}
+
+impl AstDisplay for AlterSourceStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER SOURCE ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.source_name);
+        f.write_str(" ");
+
+        match &self.action {
+            AlterSourceAction::RenameColumn {
+                column,
+                to_column_name,
+            } => {
+                f.write_str("RENAME COLUMN ");
+                f.write_node(column);
+                f.write_str(" TO ");
+                f.write_node(to_column_name);
+            }
+        }
+    }
+}
+
+impl_display!(AlterSourceStatement);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AlterViewAction {
+    SetOptions(Vec<WithOption>),
+    ResetOptions(Vec<Ident>),
+}
+
+/// `ALTER VIEW ... {RESET, SET}`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterViewStatement {
+    pub view_name: UnresolvedObjectName,
+    pub if_exists: bool,
+    pub action: AlterViewAction,
+}
+
+impl AstDisplay for AlterViewStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER VIEW ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.view_name);
+        f.write_str(" ");
+
+        match &self.action {
+            AlterViewAction::SetOptions(options) => {
+                f.write_str("SET (");
+                f.write_node(&display::comma_separated(&options));
+                f.write_str(")");
+            }
+            AlterViewAction::ResetOptions(options) => {
+                f.write_str("RESET (");
+                f.write_node(&display::comma_separated(&options));
+                f.write_str(")");
+            }
+        }
+    }
+}
+
+impl_display!(AlterViewStatement);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DiscardStatement {
     pub target: DiscardTarget,
@@ -941,6 +1073,8 @@ pub struct DropObjectsStatement {
     /// Whether `CASCADE` was specified. This will be `false` when
     /// `RESTRICT` or no drop behavior at all was specified.
     pub cascade: bool,
+    /// Options given with `WITH (...)`.
+    pub with_options: Vec<WithOption>,
 }
 
 impl AstDisplay for DropObjectsStatement {
@@ -955,6 +1089,11 @@ impl AstDisplay for DropObjectsStatement {
         if self.cascade {
             f.write_str(" CASCADE");
         }
+        if !self.with_options.is_empty() {
+            f.write_str(" WITH (");
+            f.write_node(&display::comma_separated(&self.with_options));
+            f.write_str(")");
+        }
     }
 }
 impl_display!(DropObjectsStatement);
@@ -1055,7 +1194,7 @@ impl<T: AstInfo> AstDisplay for ShowObjectsStatement<T> {
             ObjectType::Type => "TYPES",
             ObjectType::Role => "ROLES",
             ObjectType::Object => "OBJECTS",
-            ObjectType::Index => unreachable!(),
+            ObjectType::Index | ObjectType::Connection => unreachable!(),
         });
         if let Some(from) = &self.from {
             f.write_str(" FROM ");
@@ -1179,6 +1318,20 @@ impl AstDisplay for ShowCreateSinkStatement {
 }
 impl_display!(ShowCreateSinkStatement);
 
+/// `DESCRIBE CREATE SINK ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DescribeCreateSinkStatement<T: AstInfo> {
+    pub stmt: CreateSinkStatement<T>,
+}
+
+impl<T: AstInfo> AstDisplay for DescribeCreateSinkStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("DESCRIBE ");
+        f.write_node(&self.stmt);
+    }
+}
+impl_display_t!(DescribeCreateSinkStatement);
+
 /// `SHOW CREATE INDEX <index>`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ShowCreateIndexStatement {
@@ -1357,6 +1510,7 @@ pub enum ObjectType {
     Type,
     Role,
     Object,
+    Connection,
 }
 
 impl AstDisplay for ObjectType {
@@ -1371,6 +1525,7 @@ impl AstDisplay for ObjectType {
             ObjectType::Type => "TYPE",
             ObjectType::Role => "ROLE",
             ObjectType::Object => "OBJECT",
+            ObjectType::Connection => "CONNECTION",
         })
     }
 }
@@ -1573,6 +1728,20 @@ impl<T: AstInfo> AstDisplay for Assignment<T> {
 }
 impl_display_t!(Assignment);
 
+/// `EXPLAIN ENCODING FOR CREATE SOURCE ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExplainEncodingStatement<T: AstInfo> {
+    pub stmt: CreateSourceStatement<T>,
+}
+
+impl<T: AstInfo> AstDisplay for ExplainEncodingStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("EXPLAIN ENCODING FOR ");
+        f.write_node(&self.stmt);
+    }
+}
+impl_display_t!(ExplainEncodingStatement);
+
 /// Specifies what [Statement::Explain] is actually explaining
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ExplainStage {
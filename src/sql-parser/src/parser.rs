@@ -253,6 +253,7 @@ impl<'a> Parser<'a> {
                 Token::Keyword(ROLLBACK) => Ok(self.parse_rollback()?),
                 Token::Keyword(TAIL) => Ok(self.parse_tail()?),
                 Token::Keyword(EXPLAIN) => Ok(self.parse_explain()?),
+                Token::Keyword(DESCRIBE) => Ok(self.parse_describe()?),
                 Token::Keyword(DECLARE) => Ok(self.parse_declare()?),
                 Token::Keyword(FETCH) => Ok(self.parse_fetch()?),
                 Token::Keyword(CLOSE) => Ok(self.parse_close()?),
@@ -1540,7 +1541,9 @@ impl<'a> Parser<'a> {
 
     /// Parse a SQL CREATE statement
     fn parse_create(&mut self) -> Result<Statement<Raw>, ParserError> {
-        if self.peek_keyword(DATABASE) {
+        if self.peek_keyword(CONNECTION) {
+            self.parse_create_connection()
+        } else if self.peek_keyword(DATABASE) {
             self.parse_create_database()
         } else if self.peek_keyword(SCHEMA) {
             self.parse_create_schema()
@@ -1576,13 +1579,28 @@ impl<'a> Parser<'a> {
             } else {
                 self.expected(
                     self.peek_pos(),
-                    "DATABASE, SCHEMA, ROLE, USER, TYPE, INDEX, SINK, SOURCE, TABLE or [OR REPLACE] [TEMPORARY] [MATERIALIZED] VIEW or VIEWS after CREATE",
+                    "CONNECTION, DATABASE, SCHEMA, ROLE, USER, TYPE, INDEX, SINK, SOURCE, TABLE or [OR REPLACE] [TEMPORARY] [MATERIALIZED] VIEW or VIEWS after CREATE",
                     self.peek_token(),
                 )
             }
         }
     }
 
+    fn parse_create_connection(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(CONNECTION)?;
+        let if_not_exists = self.parse_if_not_exists()?;
+        let name = self.parse_object_name()?;
+        self.expect_keywords(&[FOR, KAFKA, BROKER])?;
+        let broker = self.parse_literal_string()?;
+        let with_options = self.parse_opt_with_sql_options()?;
+        Ok(Statement::CreateConnection(CreateConnectionStatement {
+            name,
+            connector: CreateConnectionConnector::Kafka { broker },
+            with_options,
+            if_not_exists,
+        }))
+    }
+
     fn parse_create_database(&mut self) -> Result<Statement<Raw>, ParserError> {
         self.expect_keyword(DATABASE)?;
         let if_not_exists = self.parse_if_not_exists()?;
@@ -1613,15 +1631,23 @@ impl<'a> Parser<'a> {
             let regex = self.parse_literal_string()?;
             Format::Regex(regex)
         } else if self.parse_keyword(CSV) {
-            self.expect_keyword(WITH)?;
-            let columns = if self.parse_keyword(HEADER) || self.parse_keyword(HEADERS) {
-                CsvColumns::Header {
-                    names: self.parse_parenthesized_column_list(Optional)?,
-                }
+            // A column specification may be omitted, in which case the
+            // column count must be inferred.
+            let columns = if self.peek_keyword(DELIMITED)
+                || (self.peek_keyword(WITH) && self.peek_nth_token(1) == Some(Token::LParen))
+            {
+                CsvColumns::Infer
             } else {
-                let n_cols = self.parse_literal_uint()? as usize;
-                self.expect_keyword(COLUMNS)?;
-                CsvColumns::Count(n_cols)
+                self.expect_keyword(WITH)?;
+                if self.parse_keyword(HEADER) || self.parse_keyword(HEADERS) {
+                    CsvColumns::Header {
+                        names: self.parse_parenthesized_column_list(Optional)?,
+                    }
+                } else {
+                    let n_cols = self.parse_literal_uint()? as usize;
+                    self.expect_keyword(COLUMNS)?;
+                    CsvColumns::Count(n_cols)
+                }
             };
             let delimiter = if self.parse_keywords(&[DELIMITED, BY]) {
                 let s = self.parse_literal_string()?;
@@ -1632,7 +1658,20 @@ impl<'a> Parser<'a> {
             } else {
                 ','
             };
-            Format::Csv { columns, delimiter }
+            // Look ahead to avoid erroring on `WITH SNAPSHOT`; we only want to
+            // accept `WITH (...)` here.
+            let with_options =
+                if self.peek_keyword(WITH) && self.peek_nth_token(1) == Some(Token::LParen) {
+                    self.expect_keyword(WITH)?;
+                    self.parse_with_options(false)?
+                } else {
+                    vec![]
+                };
+            Format::Csv {
+                columns,
+                delimiter,
+                with_options,
+            }
         } else if self.parse_keyword(JSON) {
             Format::Json
         } else if self.parse_keyword(TEXT) {
@@ -1702,6 +1741,13 @@ impl<'a> Parser<'a> {
     fn parse_csr_connector_avro(&mut self) -> Result<CsrConnectorAvro<Raw>, ParserError> {
         let url = self.parse_literal_string()?;
 
+        let reader_schema = if self.parse_keyword(READER) {
+            self.expect_keyword(SCHEMA)?;
+            Some(self.parse_literal_string()?)
+        } else {
+            None
+        };
+
         let seed = if self.parse_keyword(SEED) {
             let key_schema = if self.parse_keyword(KEY) {
                 self.expect_keyword(SCHEMA)?;
@@ -1729,6 +1775,7 @@ impl<'a> Parser<'a> {
 
         Ok(CsrConnectorAvro {
             url,
+            reader_schema,
             seed,
             with_options,
         })
@@ -1816,7 +1863,16 @@ impl<'a> Parser<'a> {
             };
             Envelope::Debezium(debezium_mode)
         } else if self.parse_keyword(UPSERT) {
-            Envelope::Upsert
+            let value_decoding_errors = if self.consume_token(&Token::LParen) {
+                self.expect_keywords(&[VALUE, DECODING, ERRORS])?;
+                self.expect_token(&Token::Eq)?;
+                self.expect_keyword(INLINE)?;
+                self.expect_token(&Token::RParen)?;
+                UpsertValueDecodingErrors::Inline
+            } else {
+                UpsertValueDecodingErrors::Fail
+            };
+            Envelope::Upsert(value_decoding_errors)
         } else if self.parse_keyword(MATERIALIZE) {
             Envelope::CdcV2
         } else {
@@ -1867,7 +1923,7 @@ impl<'a> Parser<'a> {
 
         let envelope = if self.parse_keyword(ENVELOPE) {
             let envelope = self.parse_envelope()?;
-            if matches!(envelope, Envelope::Upsert) {
+            if matches!(envelope, Envelope::Upsert(_)) {
                 // TODO: remove support for explicit UPSERT FORMAT after a period of deprecation
                 if self.parse_keyword(FORMAT) {
                     warn!("UPSERT FORMAT has been deprecated, use the new KEY FORMAT syntax");
@@ -2034,8 +2090,16 @@ impl<'a> Parser<'a> {
                 Ok(CreateSourceConnector::File { path, compression })
             }
             KAFKA => {
-                self.expect_keyword(BROKER)?;
-                let broker = self.parse_literal_string()?;
+                let connection = if self.parse_keywords(&[USING, CONNECTION]) {
+                    KafkaConnection::Reference {
+                        connection: self.parse_object_name()?,
+                    }
+                } else {
+                    self.expect_keyword(BROKER)?;
+                    KafkaConnection::Inline {
+                        broker: self.parse_literal_string()?,
+                    }
+                };
                 self.expect_keyword(TOPIC)?;
                 let topic = self.parse_literal_string()?;
                 // one token of lookahead:
@@ -2049,7 +2113,11 @@ impl<'a> Parser<'a> {
                 } else {
                     None
                 };
-                Ok(CreateSourceConnector::Kafka { broker, topic, key })
+                Ok(CreateSourceConnector::Kafka {
+                    connection,
+                    topic,
+                    key,
+                })
             }
             KINESIS => {
                 self.expect_keyword(ARN)?;
@@ -2216,7 +2284,7 @@ impl<'a> Parser<'a> {
         // Many dialects support `OR REPLACE` | `OR ALTER` right after `CREATE`, but we don't (yet).
         // ANSI SQL and Postgres support RECURSIVE here, but we don't support it either.
         let name = self.parse_object_name()?;
-        let columns = self.parse_parenthesized_column_list(Optional)?;
+        let (columns, column_types) = self.parse_view_columns()?;
         let with_options = self.parse_opt_with_sql_options()?;
         self.expect_keyword(AS)?;
         let query = self.parse_query()?;
@@ -2224,11 +2292,37 @@ impl<'a> Parser<'a> {
         Ok(ViewDefinition {
             name,
             columns,
+            column_types,
             with_options,
             query,
         })
     }
 
+    /// Parses the optional column list of a view definition, in which each
+    /// column may be followed by a data type.
+    fn parse_view_columns(
+        &mut self,
+    ) -> Result<(Vec<Ident>, Vec<Option<UnresolvedDataType>>), ParserError> {
+        if !self.consume_token(&Token::LParen) {
+            return Ok((vec![], vec![]));
+        }
+        let columns = self.parse_comma_separated(|parser| {
+            let name = parser.parse_identifier()?;
+            let data_type = match parser.peek_token() {
+                Some(Token::Comma) | Some(Token::RParen) => None,
+                _ => Some(parser.parse_data_type()?),
+            };
+            Ok((name, data_type))
+        })?;
+        self.expect_token(&Token::RParen)?;
+        let (columns, column_types): (Vec<_>, Vec<_>) = columns.into_iter().unzip();
+        if column_types.iter().all(Option::is_none) {
+            Ok((columns, vec![]))
+        } else {
+            Ok((columns, column_types))
+        }
+    }
+
     fn parse_create_views(&mut self) -> Result<Statement<Raw>, ParserError> {
         let mut if_exists = if self.parse_keyword(OR) {
             self.expect_keyword(REPLACE)?;
@@ -2356,9 +2450,10 @@ impl<'a> Parser<'a> {
         self.expect_keyword(TYPE)?;
         let name = self.parse_object_name()?;
         self.expect_keyword(AS)?;
-        let as_type = match self.expect_one_of_keywords(&[LIST, MAP])? {
+        let as_type = match self.expect_one_of_keywords(&[LIST, MAP, RANGE])? {
             LIST => CreateTypeAs::List,
             MAP => CreateTypeAs::Map,
+            RANGE => CreateTypeAs::Range,
             _ => unreachable!(),
         };
 
@@ -2417,7 +2512,12 @@ impl<'a> Parser<'a> {
                     .parse_keyword(AS)
                     .then(|| parser.parse_identifier())
                     .transpose()?;
-                Ok(SourceIncludeMetadata { ty, alias })
+                let options = if alias.is_some() && parser.peek_token() == Some(Token::LParen) {
+                    parser.parse_with_options(true)?
+                } else {
+                    vec![]
+                };
+                Ok(SourceIncludeMetadata { ty, alias, options })
             })
         } else {
             Ok(vec![])
@@ -2439,7 +2539,7 @@ impl<'a> Parser<'a> {
         let materialized = self.parse_keyword(MATERIALIZED);
 
         let object_type = match self.parse_one_of_keywords(&[
-            DATABASE, INDEX, ROLE, SCHEMA, SINK, SOURCE, TABLE, TYPE, USER, VIEW,
+            CONNECTION, DATABASE, INDEX, ROLE, SCHEMA, SINK, SOURCE, TABLE, TYPE, USER, VIEW,
         ]) {
             Some(DATABASE) => {
                 let if_exists = self.parse_if_exists()?;
@@ -2454,6 +2554,7 @@ impl<'a> Parser<'a> {
                     restrict,
                 }));
             }
+            Some(CONNECTION) => ObjectType::Connection,
             Some(INDEX) => ObjectType::Index,
             Some(ROLE) | Some(USER) => ObjectType::Role,
             Some(SCHEMA) => ObjectType::Schema,
@@ -2465,7 +2566,7 @@ impl<'a> Parser<'a> {
             _ => {
                 return self.expected(
                     self.peek_pos(),
-                    "DATABASE, INDEX, ROLE, SCHEMA, SINK, SOURCE, \
+                    "CONNECTION, DATABASE, INDEX, ROLE, SCHEMA, SINK, SOURCE, \
                      TABLE, TYPE, USER, VIEW after DROP",
                     self.peek_token(),
                 );
@@ -2478,12 +2579,18 @@ impl<'a> Parser<'a> {
             self.parse_at_most_one_keyword(&[CASCADE, RESTRICT], "DROP")?,
             Some(CASCADE),
         );
+        let with_options = if self.parse_keyword(WITH) {
+            self.parse_with_options(true)?
+        } else {
+            vec![]
+        };
         Ok(Statement::DropObjects(DropObjectsStatement {
             materialized,
             object_type,
             if_exists,
             names,
             cascade,
+            with_options,
         }))
     }
 
@@ -2755,7 +2862,7 @@ impl<'a> Parser<'a> {
         let if_exists = self.parse_if_exists()?;
         let name = self.parse_object_name()?;
 
-        // We support `ALTER INDEX ... {RESET, SET} and `ALTER <object type> RENAME
+        // We support `ALTER {INDEX, VIEW} ... {RESET, SET}` and `ALTER <object type> RENAME
         if object_type == ObjectType::Index {
             let action = match self.parse_one_of_keywords(&[RESET, SET]) {
                 Some(RESET) => {
@@ -2787,6 +2894,47 @@ impl<'a> Parser<'a> {
             }
         }
 
+        if object_type == ObjectType::View {
+            let action = match self.parse_one_of_keywords(&[RESET, SET]) {
+                Some(RESET) => {
+                    self.expect_token(&Token::LParen)?;
+                    let reset_options = self.parse_comma_separated(Parser::parse_identifier)?;
+                    self.expect_token(&Token::RParen)?;
+
+                    Some(AlterViewAction::ResetOptions(reset_options))
+                }
+                Some(SET) => {
+                    let set_options = self.parse_with_options(true)?;
+
+                    Some(AlterViewAction::SetOptions(set_options))
+                }
+                Some(_) => unreachable!(),
+                None => None,
+            };
+
+            if let Some(action) = action {
+                return Ok(Statement::AlterView(AlterViewStatement {
+                    view_name: name,
+                    if_exists,
+                    action,
+                }));
+            }
+        }
+
+        if object_type == ObjectType::Source && self.parse_keywords(&[RENAME, COLUMN]) {
+            let column = self.parse_identifier()?;
+            self.expect_keyword(TO)?;
+            let to_column_name = self.parse_identifier()?;
+            return Ok(Statement::AlterSource(AlterSourceStatement {
+                source_name: name,
+                if_exists,
+                action: AlterSourceAction::RenameColumn {
+                    column,
+                    to_column_name,
+                },
+            }));
+        }
+
         self.expect_keywords(&[RENAME, TO])?;
         let to_item_name = self.parse_identifier()?;
 
@@ -4304,6 +4452,19 @@ impl<'a> Parser<'a> {
     /// Parse an `EXPLAIN` statement, assuming that the `EXPLAIN` token
     /// has already been consumed.
     fn parse_explain(&mut self) -> Result<Statement<Raw>, ParserError> {
+        // ENCODING FOR CREATE SOURCE ...
+        if self.parse_keywords(&[ENCODING, FOR]) {
+            self.expect_keyword(CREATE)?;
+            return match self.parse_create_source()? {
+                Statement::CreateSource(stmt) => {
+                    Ok(Statement::ExplainEncoding(ExplainEncodingStatement {
+                        stmt,
+                    }))
+                }
+                _ => unreachable!(),
+            };
+        }
+
         // (TYPED)?
         let typed = self.parse_keyword(TYPED);
         let mut timing = false;
@@ -4385,6 +4546,21 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    /// Parse a `DESCRIBE` statement, assuming that the `DESCRIBE` token
+    /// has already been consumed.
+    fn parse_describe(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(CREATE)?;
+        let pos = self.peek_pos();
+        match self.parse_create_sink()? {
+            Statement::CreateSink(stmt) => {
+                Ok(Statement::DescribeCreateSink(DescribeCreateSinkStatement {
+                    stmt,
+                }))
+            }
+            _ => parser_err!(self, pos, "Expected CREATE SINK statement"),
+        }
+    }
+
     /// Parse a `DECLARE` statement, assuming that the `DECLARE` token
     /// has already been consumed.
     fn parse_declare(&mut self) -> Result<Statement<Raw>, ParserError> {
@@ -18,8 +18,8 @@ use crate::ast::visit::{self, Visit};
 use crate::ast::visit_mut::{self, VisitMut};
 use crate::ast::{
     AstInfo, CreateIndexStatement, CreateSinkStatement, CreateSourceStatement,
-    CreateTableStatement, CreateViewStatement, Expr, Ident, Query, Raw, Statement,
-    UnresolvedObjectName, ViewDefinition,
+    CreateTableStatement, CreateViewStatement, Expr, Ident, KeyConstraint, Query, Raw, SqlOption,
+    Statement, UnresolvedObjectName, Value, ViewDefinition,
 };
 use crate::names::FullName;
 
@@ -98,6 +98,65 @@ pub fn create_stmt_rename_refs(
     Ok(())
 }
 
+/// Updates references to the column `from` in the key of a `CREATE INDEX`
+/// statement to refer to the column `to` instead.
+pub fn create_index_stmt_rename_column(create_stmt: &mut Statement<Raw>, from: &Ident, to: &Ident) {
+    struct ColumnRenamer<'a> {
+        from: &'a Ident,
+        to: &'a Ident,
+    }
+
+    impl<'a, 'ast> VisitMut<'ast, Raw> for ColumnRenamer<'a> {
+        fn visit_expr_mut(&mut self, e: &'ast mut Expr<Raw>) {
+            match e {
+                Expr::Identifier(id) if id.last() == Some(self.from) => {
+                    *id.last_mut().unwrap() = self.to.clone();
+                }
+                _ => visit_mut::visit_expr_mut(self, e),
+            }
+        }
+    }
+
+    match create_stmt {
+        Statement::CreateIndex(CreateIndexStatement { key_parts, .. }) => {
+            let mut renamer = ColumnRenamer { from, to };
+            for key_part in key_parts.iter_mut().flatten() {
+                renamer.visit_expr_mut(key_part);
+            }
+        }
+        _ => unreachable!("Internal error: only indexes can have their columns renamed"),
+    }
+}
+
+/// Updates the clauses of a `CREATE SOURCE` statement that refer to the column
+/// `from` by name, namely its key constraint and its `timestamp_column`
+/// option, to refer to the column `to` instead.
+pub fn create_source_stmt_rename_column(
+    create_stmt: &mut CreateSourceStatement<Raw>,
+    from: &Ident,
+    to: &Ident,
+) {
+    if let Some(KeyConstraint::PrimaryKeyNotEnforced { columns }) = &mut create_stmt.key_constraint
+    {
+        for column in columns.iter_mut() {
+            if column == from {
+                *column = to.clone();
+            }
+        }
+    }
+    for option in &mut create_stmt.with_options {
+        match option {
+            SqlOption::Value {
+                name,
+                value: Value::String(column),
+            } if name.as_str() == "timestamp_column" && column == from.as_str() => {
+                *column = to.as_str().to_string();
+            }
+            _ => (),
+        }
+    }
+}
+
 /// Rewrites `query`'s references of `from` to `to` or errors if too ambiguous.
 fn rewrite_query(from: FullName, to: String, query: &mut Query<Raw>) -> Result<(), String> {
     let from_ident = Ident::new(from.item.clone());
@@ -17,6 +17,7 @@ use std::time::{Duration, Instant};
 
 use chrono::{DateTime, Utc, MIN_DATETIME};
 use lazy_static::lazy_static;
+use mz_dataflow_types::connections::Connector;
 use mz_dataflow_types::sources::{AwsExternalId, SourceConnector};
 
 use mz_build_info::{BuildInfo, DUMMY_BUILD_INFO};
@@ -242,6 +243,11 @@ pub trait CatalogItem {
     /// (i.e., anything other than sources), it returns an error.
     fn source_connector(&self) -> Result<&SourceConnector, CatalogError>;
 
+    /// Returns the resolved connection.
+    ///
+    /// If the catalog item is not a connection, it returns an error.
+    fn connector(&self) -> Result<&Connector, CatalogError>;
+
     /// Returns the type of the catalog item.
     fn item_type(&self) -> CatalogItemType;
 
@@ -286,6 +292,8 @@ pub enum CatalogItemType {
     Type,
     /// A func.
     Func,
+    /// A connection.
+    Connection,
 }
 
 impl fmt::Display for CatalogItemType {
@@ -298,6 +306,7 @@ impl fmt::Display for CatalogItemType {
             CatalogItemType::Index => f.write_str("index"),
             CatalogItemType::Type => f.write_str("type"),
             CatalogItemType::Func => f.write_str("func"),
+            CatalogItemType::Connection => f.write_str("connection"),
         }
     }
 }
@@ -344,6 +353,9 @@ pub enum CatalogType {
     Numeric,
     Oid,
     Pseudo,
+    Range {
+        element_id: GlobalId,
+    },
     RegClass,
     RegProc,
     RegType,
@@ -371,6 +383,8 @@ pub enum CatalogError {
     UnknownFunction(String),
     /// Unknown source.
     UnknownSource(String),
+    /// Unknown connection.
+    UnknownConnection(String),
     /// Invalid attempt to depend on a non-dependable item.
     InvalidDependency {
         /// The invalid item's name.
@@ -386,6 +400,7 @@ impl fmt::Display for CatalogError {
             Self::UnknownDatabase(name) => write!(f, "unknown database '{}'", name),
             Self::UnknownFunction(name) => write!(f, "function \"{}\" does not exist", name),
             Self::UnknownSource(name) => write!(f, "source \"{}\" does not exist", name),
+            Self::UnknownConnection(name) => write!(f, "connection \"{}\" does not exist", name),
             Self::UnknownSchema(name) => write!(f, "unknown schema '{}'", name),
             Self::UnknownRole(name) => write!(f, "unknown role '{}'", name),
             Self::UnknownItem(name) => write!(f, "unknown catalog item '{}'", name),
@@ -2122,6 +2122,9 @@ lazy_static! {
                 params!(Timestamp, String) => BinaryFunc::ToCharTimestamp, 2049;
                 params!(TimestampTz, String) => BinaryFunc::ToCharTimestampTz, 1770;
             },
+            "to_hex" => Scalar {
+                params!(Int32) => UnaryFunc::ToHexInt32(func::ToHexInt32), 2089;
+            },
             // > Returns the value as json or jsonb. Arrays and composites
             // > are converted (recursively) to arrays and objects;
             // > otherwise, if there is a cast from the type to json, the
@@ -2503,6 +2506,9 @@ lazy_static! {
         use ScalarType::*;
         use ParamType::*;
         builtins! {
+            "bit_count" => Scalar {
+                params!(Int32) => UnaryFunc::BitCountInt32(func::BitCountInt32), oid::FUNC_BIT_COUNT_INT32_OID;
+            },
             "csv_extract" => Table {
                 params!(Int64, String) => Operation::binary(move |_ecx, ncols, input| {
                     let ncols = match ncols.into_literal_int64() {
@@ -2527,6 +2533,9 @@ lazy_static! {
             "current_timestamp" => Scalar {
                 params!() => NullaryFunc::CurrentTimestamp, oid::FUNC_CURRENT_TIMESTAMP_OID;
             },
+            "float4_exact" => Scalar {
+                params!(Int32) => UnaryFunc::TryCastInt32ToFloat32(func::TryCastInt32ToFloat32) => Float32, oid::FUNC_FLOAT4_EXACT_OID;
+            },
             "list_agg" => Aggregate {
                 params!(Any) => Operation::unary_ordered(|ecx, e, order_by| {
                     if let ScalarType::Char {.. }  = ecx.scalar_type(&e) {
@@ -9,7 +9,7 @@
 
 //! Provides parsing and convenience functions for working with Kafka from the `sql` package.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::convert::{self, TryInto};
 use std::fs::File;
 use std::io::Read;
@@ -17,7 +17,10 @@ use std::sync::{Arc, Mutex};
 
 use anyhow::bail;
 
+use mz_dataflow_types::connections::{Connector, KafkaConnector};
+use mz_expr::GlobalId;
 use mz_kafka_util::client::MzClientContext;
+use mz_ore::str::StrExt;
 use mz_ore::task;
 use rdkafka::client::ClientContext;
 use rdkafka::consumer::{BaseConsumer, Consumer, ConsumerContext};
@@ -26,13 +29,19 @@ use reqwest::Url;
 use tokio::time::Duration;
 
 use mz_ccsr::tls::{Certificate, Identity};
-use mz_sql_parser::ast::Value;
+use mz_sql_parser::ast::{UnresolvedObjectName, Value};
+
+use crate::catalog::SessionCatalog;
+use crate::normalize;
+use crate::plan::PlanNotice;
 
 enum ValType {
     Path,
     String,
     // Number with range [lower, upper]
     Number(i32, i32),
+    // String that must be one of the listed values
+    OneOf(&'static [&'static str]),
     Boolean,
     EnvVar,
 }
@@ -52,6 +61,19 @@ impl ValType {
                 Ok(parsed_n) if *lower <= parsed_n && parsed_n <= *upper => n.to_string(),
                 _ => bail!("must be a number between {} and {}", lower, upper),
             },
+            (ValType::OneOf(values), Value::String(v)) => {
+                if !values.contains(&v.as_str()) {
+                    bail!(
+                        "must be one of {}",
+                        values
+                            .iter()
+                            .map(|v| v.quoted().to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                }
+                v.to_string()
+            }
             (ValType::EnvVar, Value::String(v)) => std::env::var(v)?,
             _ => bail!("unexpected value type"),
         })
@@ -196,54 +218,203 @@ fn extract(
 pub fn extract_config(
     with_options: &mut BTreeMap<String, Value>,
 ) -> Result<BTreeMap<String, String>, anyhow::Error> {
-    extract(
-        with_options,
-        &[
-            Config::string("acks"),
-            Config::string("client_id"),
-            Config::new(
-                "statistics_interval_ms",
-                // The range of values comes from `statistics.interval.ms` in
-                // https://github.com/edenhill/librdkafka/blob/master/CONFIGURATION.md
-                ValType::Number(0, 86_400_000),
-            )
-            .set_default(Some(
-                chrono::Duration::seconds(1).num_milliseconds().to_string(),
-            )),
-            Config::new(
-                "topic_metadata_refresh_interval_ms",
-                // The range of values comes from `topic.metadata.refresh.interval.ms` in
-                // https://github.com/edenhill/librdkafka/blob/master/CONFIGURATION.md
-                ValType::Number(0, 3_600_000),
-            ),
-            Config::new("enable_auto_commit", ValType::Boolean),
-            Config::string("isolation_level").set_default(Some(String::from("read_committed"))),
-            Config::string("security_protocol"),
-            Config::path("sasl_kerberos_keytab"),
-            Config::string("sasl_username"),
-            Config::string("sasl_password").include_env_var(),
-            Config::string("sasl_kerberos_kinit_cmd"),
-            Config::string("sasl_kerberos_min_time_before_relogin"),
-            Config::string("sasl_kerberos_principal"),
-            Config::string("sasl_kerberos_service_name"),
-            // For historical reasons, we allow `sasl_mechanisms` to be lowercase or
-            // mixed case, while librdkafka requires all uppercase (e.g., `PLAIN`,
-            // not `plain`).
-            Config::string("sasl_mechanisms").set_transform(|s| s.to_uppercase()),
-            Config::path("ssl_ca_location"),
-            Config::path("ssl_certificate_location"),
-            Config::path("ssl_key_location"),
-            Config::string("ssl_key_password").include_env_var(),
-            Config::new("transaction_timeout_ms", ValType::Number(0, i32::MAX)),
-            Config::new("enable_idempotence", ValType::Boolean),
-            Config::new(
-                "fetch_message_max_bytes",
-                // The range of values comes from `fetch.message.max.bytes` in
-                // https://github.com/edenhill/librdkafka/blob/master/CONFIGURATION.md
-                ValType::Number(0, 1_000_000_000),
-            ),
-        ],
-    )
+    extract(with_options, &kafka_configs())
+}
+
+/// The Kafka options that may be set in the `with_options` of a `CREATE SOURCE`
+/// or `CREATE SINK` statement.
+fn kafka_configs() -> Vec<Config> {
+    vec![
+        Config::string("acks"),
+        Config::string("client_id"),
+        Config::new(
+            "statistics_interval_ms",
+            // The range of values comes from `statistics.interval.ms` in
+            // https://github.com/edenhill/librdkafka/blob/master/CONFIGURATION.md
+            ValType::Number(0, 86_400_000),
+        )
+        .set_default(Some(
+            chrono::Duration::seconds(1).num_milliseconds().to_string(),
+        )),
+        Config::new(
+            "topic_metadata_refresh_interval_ms",
+            // The range of values comes from `topic.metadata.refresh.interval.ms` in
+            // https://github.com/edenhill/librdkafka/blob/master/CONFIGURATION.md
+            ValType::Number(0, 3_600_000),
+        ),
+        Config::new("enable_auto_commit", ValType::Boolean),
+        Config::new(
+            "isolation_level",
+            ValType::OneOf(&["read_committed", "read_uncommitted"]),
+        )
+        .set_default(Some(String::from("read_committed"))),
+        Config::string("security_protocol"),
+        Config::path("sasl_kerberos_keytab"),
+        Config::string("sasl_username"),
+        Config::string("sasl_password").include_env_var(),
+        Config::string("sasl_kerberos_kinit_cmd"),
+        Config::string("sasl_kerberos_min_time_before_relogin"),
+        Config::string("sasl_kerberos_principal"),
+        Config::string("sasl_kerberos_service_name"),
+        // For historical reasons, we allow `sasl_mechanisms` to be lowercase or
+        // mixed case, while librdkafka requires all uppercase (e.g., `PLAIN`,
+        // not `plain`).
+        Config::string("sasl_mechanisms").set_transform(|s| s.to_uppercase()),
+        Config::path("ssl_ca_location"),
+        Config::path("ssl_certificate_location"),
+        Config::path("ssl_key_location"),
+        Config::string("ssl_key_password").include_env_var(),
+        Config::new("transaction_timeout_ms", ValType::Number(0, i32::MAX)),
+        Config::new("enable_idempotence", ValType::Boolean),
+        Config::new(
+            "fetch_message_max_bytes",
+            // The range of values comes from `fetch.message.max.bytes` in
+            // https://github.com/edenhill/librdkafka/blob/master/CONFIGURATION.md
+            ValType::Number(0, 1_000_000_000),
+        ),
+    ]
+}
+
+/// Removes from `with_options` any key that is not the name of a Kafka option,
+/// but looks like it was meant to be one, and returns a notice for each that
+/// names the option it resembles.
+///
+/// With `strict_kafka_options = true`, such a key is an error instead. Keys
+/// that do not resemble any option are left for the caller, which reports
+/// them along with its other unexpected options.
+pub fn take_misspelled_options(
+    with_options: &mut BTreeMap<String, Value>,
+) -> Result<Vec<PlanNotice>, anyhow::Error> {
+    let strict = match with_options.remove("strict_kafka_options") {
+        None => false,
+        Some(Value::Boolean(b)) => b,
+        Some(_) => bail!("strict_kafka_options must be a boolean"),
+    };
+    let mut names = vec![];
+    for config in kafka_configs() {
+        names.push(config.name.to_string());
+        if config.include_env_var {
+            names.push(config.get_env_var_key());
+        }
+    }
+    let mut notices = vec![];
+    for key in with_options.keys().cloned().collect::<Vec<_>>() {
+        if names.contains(&key) {
+            continue;
+        }
+        // Users familiar with librdkafka often spell options with dots, e.g.
+        // `fetch.message.max.bytes`, rather than underscores.
+        let undotted = key.replace(".", "_");
+        let suggestion = names
+            .iter()
+            .filter(|name| {
+                **name == undotted || (key.len() > 4 && edit_distance(name, &undotted) <= 2)
+            })
+            .min_by_key(|name| edit_distance(name, &undotted));
+        if let Some(suggestion) = suggestion {
+            if strict {
+                bail!(
+                    "unrecognized Kafka option {}; did you mean {}?",
+                    key.quoted(),
+                    suggestion.quoted()
+                );
+            }
+            with_options.remove(&key);
+            notices.push(PlanNotice::MisspelledKafkaOption {
+                option: key,
+                suggestion: suggestion.clone(),
+            });
+        }
+    }
+    Ok(notices)
+}
+
+/// Computes the Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                prev
+            } else {
+                1 + prev.min(cur).min(row[j])
+            };
+            prev = cur;
+        }
+    }
+    row[b.len()]
+}
+
+/// Like [`extract_config`], but for a source that uses the named Kafka
+/// connection `connector`.
+///
+/// Options specified in `with_options` take precedence over those stored with
+/// the connection.
+pub fn extract_config_with_connection(
+    with_options: &mut BTreeMap<String, Value>,
+    connector: &KafkaConnector,
+) -> Result<BTreeMap<String, String>, anyhow::Error> {
+    let specified: BTreeSet<String> = with_options.keys().cloned().collect();
+    let mut out = extract_config(with_options)?;
+    // Drop the defaults filled in by `extract_config`, so that they do not
+    // shadow the connection's options.
+    out.retain(|key, _| {
+        let name = key.replace(".", "_");
+        specified.contains(&name) || specified.contains(&format!("{}_env", name))
+    });
+    for (key, value) in &connector.config_options {
+        out.entry(key.clone()).or_insert_with(|| value.clone());
+    }
+    Ok(out)
+}
+
+/// Verifies that `topic` is a legal Kafka topic name.
+///
+/// Kafka limits topic names to 249 ASCII alphanumerics, `.`, `_`, and `-`,
+/// and reserves the names `.` and `..`.
+pub fn validate_topic_name(topic: &str) -> Result<(), anyhow::Error> {
+    if topic.is_empty() || topic.len() > 249 {
+        bail!(
+            "invalid topic name {}: must be between 1 and 249 characters",
+            topic.quoted()
+        );
+    }
+    if topic == "." || topic == ".." {
+        bail!("invalid topic name {}", topic.quoted());
+    }
+    if let Some(c) = topic
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-')))
+    {
+        bail!(
+            "invalid topic name {}: illegal character {}",
+            topic.quoted(),
+            c.escape_default()
+        );
+    }
+    Ok(())
+}
+
+/// Resolves `name` to a Kafka connection, returning the connection's ID and
+/// its details.
+///
+/// # Errors
+///
+/// - If `name` does not refer to a catalog item.
+/// - If the catalog item is not a Kafka connection.
+pub fn resolve_connection(
+    catalog: &dyn SessionCatalog,
+    name: &UnresolvedObjectName,
+) -> Result<(GlobalId, KafkaConnector), anyhow::Error> {
+    let item = catalog.resolve_item(&normalize::unresolved_object_name(name.clone())?)?;
+    match item.connector() {
+        Ok(Connector::Kafka(connector)) => Ok((item.id(), connector.clone())),
+        Err(_) => bail!("{} is not a Kafka connection", item.name()),
+    }
 }
 
 /// Create a new `rdkafka::ClientConfig` with the provided
@@ -323,10 +494,13 @@ pub async fn create_consumer(
 /// partition. If no such message exists (or the Kafka broker is before
 /// 0.10.0), the current end offset is returned for the partition.
 ///
-/// The provided `kafka_time_offset` option must be a non-zero number:
+/// The provided `kafka_time_offset` option must be a non-zero number or a
+/// negative interval:
 /// * Non-Negative numbers will used as is (e.g. `1622659034343`)
 /// * Negative numbers will be translated to a timestamp in millis
 ///   before now (e.g. `-10` means 10 millis ago)
+/// * Negative intervals will be translated to a timestamp that long
+///   before now (e.g. `'-10m'` means 10 minutes ago)
 ///
 /// If `kafka_time_offset` has not been configured, an empty Option is
 /// returned.
@@ -357,9 +531,27 @@ pub async fn lookup_start_offsets(
             }
             // Timestamp in millis (e.g. 1622659034343)
             Ok(ts) => ts,
-            _ => bail!("`kafka_time_offset` must be a number"),
+            _ => bail!("`kafka_time_offset` must be a number or an interval"),
         },
-        _ => bail!("`kafka_time_offset` must be a number"),
+        // Interval before now (e.g. '-10m' means 10 minutes ago)
+        Value::String(s) => {
+            let interval = match mz_repr::strconv::parse_interval(s) {
+                Ok(interval) if interval.months == 0 => interval,
+                Ok(_) => bail!("`kafka_time_offset` interval cannot contain months or years"),
+                Err(_) => bail!("`kafka_time_offset` must be a number or an interval"),
+            };
+            let offset = interval.duration_as_chrono().num_milliseconds();
+            if offset >= 0 {
+                bail!("`kafka_time_offset` interval must be negative, as it cannot refer to the future")
+            }
+            let now: i64 = now.try_into()?;
+            let ts = now + offset;
+            if ts <= 0 {
+                bail!("Relative `kafka_time_offset` must be smaller than current system timestamp")
+            }
+            ts
+        }
+        _ => bail!("`kafka_time_offset` must be a number or an interval"),
     };
 
     // Lookup offsets
@@ -410,6 +602,47 @@ pub async fn lookup_start_offsets(
     .await?
 }
 
+/// Verifies that `topic` has the number of partitions given by the provided
+/// `expected_partition_count` option, if that option is set to a positive
+/// integer.
+///
+/// Invalid values are ignored here and reported during planning.
+pub async fn validate_partition_count(
+    consumer: Arc<BaseConsumer<KafkaErrCheckContext>>,
+    topic: &str,
+    with_options: &BTreeMap<String, Value>,
+) -> Result<(), anyhow::Error> {
+    let expected = match with_options.get("expected_partition_count") {
+        Some(Value::Number(n)) => match n.parse::<usize>() {
+            Ok(n) if n > 0 => n,
+            _ => return Ok(()),
+        },
+        _ => return Ok(()),
+    };
+
+    task::spawn_blocking(|| format!("kafka_validate_partition_count:{topic}"), {
+        let topic = topic.to_string();
+        move || {
+            let actual = mz_kafka_util::client::get_partitions(
+                consumer.as_ref().client(),
+                &topic,
+                Duration::from_secs(10),
+            )?
+            .len();
+            if actual != expected {
+                bail!(
+                    "topic {} has {} partitions, but expected_partition_count is {}",
+                    topic,
+                    actual,
+                    expected
+                );
+            }
+            Ok(())
+        }
+    })
+    .await?
+}
+
 // Kafka supports bulk lookup of watermarks, but it is not exposed in rdkafka.
 // If that ever changes, we will want to first collect all pids that have no
 // offset for a given timestamp and then do a single request (instead of doing
@@ -460,6 +693,70 @@ impl ClientContext for KafkaErrCheckContext {
     }
 }
 
+/// How the schema registry subject of a topic's keys or values is named.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubjectNameStrategy {
+    /// The subject is `<topic>-key` or `<topic>-value`.
+    TopicName,
+    /// The subject is the fully-qualified name of the record.
+    RecordName(String),
+    /// The subject is `<topic>-<record name>`.
+    TopicRecordName(String),
+}
+
+impl SubjectNameStrategy {
+    /// Returns the subject for the keys or values of `topic`, as indicated by
+    /// `kind`, which must be `"key"` or `"value"`.
+    pub fn subject(&self, topic: &str, kind: &str) -> String {
+        match self {
+            SubjectNameStrategy::TopicName => format!("{}-{}", topic, kind),
+            SubjectNameStrategy::RecordName(record) => record.clone(),
+            SubjectNameStrategy::TopicRecordName(record) => format!("{}-{}", topic, record),
+        }
+    }
+}
+
+/// Extracts the subject name strategy for keys or values, as indicated by
+/// `kind`, from the `<kind>_subject_strategy` and `<kind>_record_name`
+/// options.
+pub fn extract_subject_name_strategy(
+    ccsr_options: &mut BTreeMap<String, Value>,
+    kind: &str,
+) -> Result<SubjectNameStrategy, anyhow::Error> {
+    let strategy_option = format!("{}_subject_strategy", kind);
+    let record_option = format!("{}_record_name", kind);
+    let strategy = match ccsr_options.remove(&strategy_option) {
+        None => "topic_name".to_string(),
+        Some(Value::String(s)) => s.to_lowercase(),
+        Some(_) => bail!("{} must be a string", strategy_option),
+    };
+    let record = match ccsr_options.remove(&record_option) {
+        None => None,
+        Some(Value::String(s)) => Some(s),
+        Some(_) => bail!("{} must be a string", record_option),
+    };
+    match (strategy.as_str(), record) {
+        ("topic_name", None) => Ok(SubjectNameStrategy::TopicName),
+        ("topic_name", Some(_)) => bail!(
+            "{} requires {} = 'record_name' or 'topic_record_name'",
+            record_option,
+            strategy_option
+        ),
+        ("record_name", Some(record)) => Ok(SubjectNameStrategy::RecordName(record)),
+        ("topic_record_name", Some(record)) => Ok(SubjectNameStrategy::TopicRecordName(record)),
+        ("record_name" | "topic_record_name", None) => bail!(
+            "{} = '{}' requires {}",
+            strategy_option,
+            strategy,
+            record_option
+        ),
+        _ => bail!(
+            "{} must be one of 'topic_name', 'record_name' or 'topic_record_name'",
+            strategy_option
+        ),
+    }
+}
+
 // Generates a `ccsr::ClientConfig` based on the configuration extracted from
 // `extract_security_config()`. Currently only supports SSL auth.
 pub fn generate_ccsr_client_config(
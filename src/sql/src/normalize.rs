@@ -24,10 +24,11 @@ use mz_repr::ColumnName;
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
-    AstInfo, CreateIndexStatement, CreateSinkStatement, CreateSourceStatement,
-    CreateTableStatement, CreateTypeStatement, CreateViewStatement, Function, FunctionArgs, Ident,
-    IfExistsBehavior, Op, Query, Raw, SqlOption, Statement, TableFactor, TableFunction,
-    UnresolvedObjectName, Value, ViewDefinition,
+    AstInfo, CreateConnectionStatement, CreateIndexStatement, CreateSinkStatement,
+    CreateSourceConnector, CreateSourceStatement, CreateTableStatement, CreateTypeStatement,
+    CreateViewStatement, Function, FunctionArgs, Ident, IfExistsBehavior, KafkaConnection, Op,
+    Query, Raw, SqlOption, Statement, TableFactor, TableFunction, UnresolvedObjectName, Value,
+    ViewDefinition,
 };
 
 use crate::names::{resolve_names_stmt, Aug, DatabaseSpecifier, FullName, PartialName};
@@ -271,7 +272,7 @@ pub fn create_statement(
         Statement::CreateSource(CreateSourceStatement {
             name,
             col_names: _,
-            connector: _,
+            connector,
             with_options: _,
             format: _,
             include_metadata: _,
@@ -281,10 +282,27 @@ pub fn create_statement(
             key_constraint: _,
         }) => {
             *name = allocate_name(name)?;
+            if let CreateSourceConnector::Kafka {
+                connection: KafkaConnection::Reference { connection },
+                ..
+            } = connector
+            {
+                *connection = resolve_item(connection)?;
+            }
             *if_not_exists = false;
             *materialized = false;
         }
 
+        Statement::CreateConnection(CreateConnectionStatement {
+            name,
+            connector: _,
+            with_options: _,
+            if_not_exists,
+        }) => {
+            *name = allocate_name(name)?;
+            *if_not_exists = false;
+        }
+
         Statement::CreateTable(CreateTableStatement {
             name,
             columns,
@@ -333,6 +351,7 @@ pub fn create_statement(
                     name,
                     query,
                     columns: _,
+                    column_types,
                     with_options: _,
                 },
         }) => {
@@ -343,6 +362,9 @@ pub fn create_statement(
             };
             {
                 let mut normalizer = QueryNormalizer::new(scx);
+                for data_type in column_types.iter_mut().flatten() {
+                    normalizer.visit_data_type_mut(data_type);
+                }
                 normalizer.visit_query_mut(query);
                 if let Some(err) = normalizer.err {
                     return Err(err.into());
@@ -27,6 +27,7 @@
 // statement.
 
 use std::collections::HashMap;
+use std::fmt;
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
@@ -34,10 +35,12 @@ use enum_kinds::EnumKind;
 use serde::{Deserialize, Serialize};
 
 use mz_dataflow_types::{
-    sinks::SinkConnectorBuilder, sinks::SinkEnvelope, sources::SourceConnector,
+    connections::Connector, sinks::SinkConnectorBuilder, sinks::SinkEnvelope,
+    sources::SourceConnector,
 };
 use mz_expr::{GlobalId, MirRelationExpr, MirScalarExpr, RowSetFinishing};
 use mz_ore::now::{self, NOW_ZERO};
+use mz_ore::str::StrExt;
 use mz_repr::{ColumnName, Diff, RelationDesc, Row, ScalarType};
 
 use crate::ast::{
@@ -70,6 +73,7 @@ pub use statement::{describe, plan, plan_copy_from, StatementContext, StatementD
 /// Instructions for executing a SQL query.
 #[derive(Debug)]
 pub enum Plan {
+    CreateConnection(CreateConnectionPlan),
     CreateDatabase(CreateDatabasePlan),
     CreateSchema(CreateSchemaPlan),
     CreateRole(CreateRolePlan),
@@ -105,6 +109,9 @@ pub enum Plan {
     AlterIndexResetOptions(AlterIndexResetOptionsPlan),
     AlterIndexEnable(AlterIndexEnablePlan),
     AlterItemRename(AlterItemRenamePlan),
+    AlterSourceRenameColumn(AlterSourceRenameColumnPlan),
+    AlterViewSetOptions(AlterViewSetOptionsPlan),
+    AlterViewResetOptions(AlterViewResetOptionsPlan),
     Declare(DeclarePlan),
     Fetch(FetchPlan),
     Close(ClosePlan),
@@ -119,6 +126,13 @@ pub struct StartTransactionPlan {
     pub access: Option<TransactionAccessMode>,
 }
 
+#[derive(Debug)]
+pub struct CreateConnectionPlan {
+    pub name: FullName,
+    pub if_not_exists: bool,
+    pub connection: Connection,
+}
+
 #[derive(Debug)]
 pub struct CreateDatabasePlan {
     pub name: String,
@@ -143,6 +157,7 @@ pub struct CreateSourcePlan {
     pub source: Source,
     pub if_not_exists: bool,
     pub materialized: bool,
+    pub notices: Vec<PlanNotice>,
 }
 
 #[derive(Debug)]
@@ -151,6 +166,55 @@ pub struct CreateSinkPlan {
     pub sink: Sink,
     pub with_snapshot: bool,
     pub if_not_exists: bool,
+    pub notices: Vec<PlanNotice>,
+}
+
+/// A condition noticed while planning a statement that does not prevent the
+/// statement from executing, but that the user should be made aware of.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PlanNotice {
+    /// The upsert key of a sink was declared `NOT ENFORCED`, and so has not
+    /// been verified to be a unique key of the sinked relation.
+    UpsertKeyNotEnforced {
+        sink_name: FullName,
+        key: Vec<ColumnName>,
+    },
+    /// A source was created with `CREATE MATERIALIZED SOURCE`, which is
+    /// deprecated in favor of creating the source and an index separately.
+    MaterializedSourceDeprecated { source_name: FullName },
+    /// A Kafka source or sink was given an option that is not a Kafka option,
+    /// but resembles one, and so was ignored.
+    MisspelledKafkaOption { option: String, suggestion: String },
+}
+
+impl fmt::Display for PlanNotice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PlanNotice::UpsertKeyNotEnforced { sink_name, key } => write!(
+                f,
+                "verification of upsert key ({}) disabled for sink '{}' via 'NOT ENFORCED'. \
+                This is potentially dangerous and can lead to crashing materialize when the \
+                specified key is not in fact a unique key of the sinked view.",
+                key.iter()
+                    .map(|c| c.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                sink_name
+            ),
+            PlanNotice::MaterializedSourceDeprecated { source_name } => write!(
+                f,
+                "CREATE MATERIALIZED SOURCE is deprecated; instead of materializing source '{}', \
+                create it with CREATE SOURCE and then run CREATE DEFAULT INDEX ON {}",
+                source_name, source_name
+            ),
+            PlanNotice::MisspelledKafkaOption { option, suggestion } => write!(
+                f,
+                "ignoring unrecognized Kafka option {}; did you mean {}?",
+                option.quoted(),
+                suggestion.quoted()
+            ),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -211,6 +275,9 @@ pub struct DropRolesPlan {
 pub struct DropItemsPlan {
     pub items: Vec<GlobalId>,
     pub ty: ObjectType,
+    /// Whether to drop the upstream replication slots of dropped Postgres
+    /// sources.
+    pub drop_slot: bool,
 }
 
 #[derive(Debug)]
@@ -317,6 +384,31 @@ pub struct AlterIndexEnablePlan {
     pub id: GlobalId,
 }
 
+/// Sets options on every index that materializes the view `id`.
+#[derive(Debug)]
+pub struct AlterViewSetOptionsPlan {
+    pub id: GlobalId,
+    pub options: Vec<IndexOption>,
+}
+
+/// Resets options on every index that materializes the view `id`.
+#[derive(Debug)]
+pub struct AlterViewResetOptionsPlan {
+    pub id: GlobalId,
+    pub options: Vec<IndexOptionName>,
+}
+
+#[derive(Debug)]
+pub struct AlterSourceRenameColumnPlan {
+    pub id: GlobalId,
+    /// The source's `CREATE` statement, updated to use the new column name.
+    pub create_sql: String,
+    /// The source's description, updated to use the new column name.
+    pub desc: RelationDesc,
+    /// The IDs and updated `CREATE` statements of the source's indexes.
+    pub indexes: Vec<(GlobalId, String)>,
+}
+
 #[derive(Debug)]
 pub struct AlterItemRenamePlan {
     pub id: GlobalId,
@@ -375,6 +467,13 @@ pub struct Source {
     pub connector: SourceConnector,
     pub desc: RelationDesc,
     pub expr: mz_expr::MirRelationExpr,
+    pub depends_on: Vec<GlobalId>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Connection {
+    pub create_sql: String,
+    pub connector: Connector,
 }
 
 #[derive(Clone, Debug)]
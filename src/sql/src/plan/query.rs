@@ -730,6 +730,36 @@ where
     Ok(expr.map(map_exprs).project(project_key))
 }
 
+/// Casts the columns of a view's query to the types declared in the view's
+/// column list.
+///
+/// The length of `target_types` must match the arity of `expr`. Columns
+/// without a declared type are left unchanged.
+pub fn plan_view_column_casts(
+    scx: &StatementContext,
+    expr: HirRelationExpr,
+    desc: &RelationDesc,
+    target_types: &[Option<ScalarType>],
+) -> Result<(HirRelationExpr, RelationDesc), PlanError> {
+    let qcx = QueryContext::root(scx, QueryLifetime::Static);
+    let typ = qcx.relation_type(&expr);
+    let target_types: Vec<ScalarType> = target_types
+        .iter()
+        .zip_eq(typ.column_types)
+        .map(|(target, current)| target.clone().unwrap_or(current.scalar_type))
+        .collect();
+    let expr = cast_relation(&qcx, CastContext::Explicit, expr, &target_types).map_err(|e| {
+        PlanError::Unstructured(format!(
+            "cannot cast column {} of type {} to declared type {}",
+            desc.get_name(e.column).as_str().quoted(),
+            scx.humanize_scalar_type(&e.source_type),
+            scx.humanize_scalar_type(&e.target_type),
+        ))
+    })?;
+    let desc = RelationDesc::new(qcx.relation_type(&expr), desc.iter_names().cloned());
+    Ok((expr, desc))
+}
+
 /// Plans an expression in the AS OF position of a `SELECT` or `TAIL` statement.
 pub fn plan_as_of<'a>(
     scx: &'a StatementContext,
@@ -761,14 +791,44 @@ pub fn plan_default_expr(
     scx: &StatementContext,
     expr: &Expr<Raw>,
     target_ty: &ScalarType,
+) -> Result<(HirScalarExpr, Vec<GlobalId>), PlanError> {
+    plan_default_expr_in_scope(
+        scx,
+        expr,
+        target_ty,
+        &Scope::empty(),
+        &RelationType::empty(),
+    )
+}
+
+/// Like [`plan_default_expr`], but resolves column references against the
+/// columns of the table `table_name`, described by `desc`. The caller is
+/// responsible for rejecting any column references in the planned expression.
+pub fn plan_column_default_expr(
+    scx: &StatementContext,
+    expr: &Expr<Raw>,
+    target_ty: &ScalarType,
+    table_name: &PartialName,
+    desc: &RelationDesc,
+) -> Result<(HirScalarExpr, Vec<GlobalId>), PlanError> {
+    let scope = Scope::from_source(Some(table_name.clone()), desc.iter_names());
+    plan_default_expr_in_scope(scx, expr, target_ty, &scope, desc.typ())
+}
+
+fn plan_default_expr_in_scope(
+    scx: &StatementContext,
+    expr: &Expr<Raw>,
+    target_ty: &ScalarType,
+    scope: &Scope,
+    relation_type: &RelationType,
 ) -> Result<(HirScalarExpr, Vec<GlobalId>), PlanError> {
     let mut qcx = QueryContext::root(scx, QueryLifetime::OneShot(scx.pcx()?));
     let expr = resolve_names_expr(&mut qcx, expr.clone())?;
     let ecx = &ExprContext {
         qcx: &qcx,
         name: "DEFAULT expression",
-        scope: &Scope::empty(),
-        relation_type: &RelationType::empty(),
+        scope,
+        relation_type,
         allow_aggregates: false,
         allow_subqueries: false,
         allow_windows: false,
@@ -4308,6 +4368,9 @@ fn scalar_type_from_catalog(
                 CatalogType::Pseudo => {
                     sql_bail!("cannot reference pseudo type {}", entry.name().to_string())
                 }
+                CatalogType::Range { .. } => {
+                    bail_unsupported!(format!("range type {}", entry.name()))
+                }
                 CatalogType::RegClass => Ok(ScalarType::RegClass),
                 CatalogType::RegProc => Ok(ScalarType::RegProc),
                 CatalogType::RegType => Ok(ScalarType::RegType),
@@ -101,6 +101,7 @@ pub fn describe(
 
     let desc = match stmt {
         // DDL statements.
+        Statement::CreateConnection(stmt) => ddl::describe_create_connection(&scx, stmt)?,
         Statement::CreateDatabase(stmt) => ddl::describe_create_database(&scx, stmt)?,
         Statement::CreateSchema(stmt) => ddl::describe_create_schema(&scx, stmt)?,
         Statement::CreateTable(stmt) => ddl::describe_create_table(&scx, stmt)?,
@@ -108,6 +109,8 @@ pub fn describe(
         Statement::CreateView(stmt) => ddl::describe_create_view(&scx, stmt)?,
         Statement::CreateViews(stmt) => ddl::describe_create_views(&scx, stmt)?,
         Statement::CreateSink(stmt) => ddl::describe_create_sink(&scx, stmt)?,
+        Statement::DescribeCreateSink(stmt) => ddl::describe_describe_create_sink(&scx, stmt)?,
+        Statement::ExplainEncoding(stmt) => ddl::describe_explain_encoding(&scx, stmt)?,
         Statement::CreateIndex(stmt) => ddl::describe_create_index(&scx, stmt)?,
         Statement::CreateType(stmt) => ddl::describe_create_type(&scx, stmt)?,
         Statement::CreateRole(stmt) => ddl::describe_create_role(&scx, stmt)?,
@@ -115,6 +118,8 @@ pub fn describe(
         Statement::DropObjects(stmt) => ddl::describe_drop_objects(&scx, stmt)?,
         Statement::AlterObjectRename(stmt) => ddl::describe_alter_object_rename(&scx, stmt)?,
         Statement::AlterIndex(stmt) => ddl::describe_alter_index_options(&scx, stmt)?,
+        Statement::AlterSource(stmt) => ddl::describe_alter_source(&scx, stmt)?,
+        Statement::AlterView(stmt) => ddl::describe_alter_view_options(&scx, stmt)?,
 
         // `SHOW` statements.
         Statement::ShowColumns(stmt) => show::show_columns(&scx, stmt)?.describe()?,
@@ -188,6 +193,7 @@ pub fn plan(
 
     match stmt {
         // DDL statements.
+        Statement::CreateConnection(stmt) => ddl::plan_create_connection(scx, stmt),
         Statement::CreateDatabase(stmt) => ddl::plan_create_database(scx, stmt),
         Statement::CreateSchema(stmt) => ddl::plan_create_schema(scx, stmt),
         Statement::CreateTable(stmt) => ddl::plan_create_table(scx, stmt),
@@ -195,12 +201,16 @@ pub fn plan(
         Statement::CreateView(stmt) => ddl::plan_create_view(scx, stmt, params),
         Statement::CreateViews(stmt) => ddl::plan_create_views(scx, stmt),
         Statement::CreateSink(stmt) => ddl::plan_create_sink(scx, stmt),
+        Statement::DescribeCreateSink(stmt) => ddl::plan_describe_create_sink(scx, stmt),
+        Statement::ExplainEncoding(stmt) => ddl::plan_explain_encoding(scx, stmt),
         Statement::CreateIndex(stmt) => ddl::plan_create_index(scx, stmt),
         Statement::CreateType(stmt) => ddl::plan_create_type(scx, stmt),
         Statement::CreateRole(stmt) => ddl::plan_create_role(scx, stmt),
         Statement::DropDatabase(stmt) => ddl::plan_drop_database(scx, stmt),
         Statement::DropObjects(stmt) => ddl::plan_drop_objects(scx, stmt),
         Statement::AlterIndex(stmt) => ddl::plan_alter_index_options(scx, stmt),
+        Statement::AlterSource(stmt) => ddl::plan_alter_source(scx, stmt),
+        Statement::AlterView(stmt) => ddl::plan_alter_view_options(scx, stmt),
         Statement::AlterObjectRename(stmt) => ddl::plan_alter_object_rename(scx, stmt),
 
         // DML statements.
@@ -266,7 +276,8 @@ impl PartialEq<ObjectType> for CatalogItemType {
             | (CatalogItemType::Sink, ObjectType::Sink)
             | (CatalogItemType::View, ObjectType::View)
             | (CatalogItemType::Index, ObjectType::Index)
-            | (CatalogItemType::Type, ObjectType::Type) => true,
+            | (CatalogItemType::Type, ObjectType::Type)
+            | (CatalogItemType::Connection, ObjectType::Connection) => true,
             (_, _) => false,
         }
     }
@@ -24,45 +24,51 @@ use globset::GlobBuilder;
 use itertools::Itertools;
 use regex::Regex;
 use reqwest::Url;
-use tracing::{debug, warn};
+use tracing::debug;
 
 use mz_dataflow_types::{
+    connections::{Connector, KafkaConnector},
     sinks::{
         AvroOcfSinkConnectorBuilder, KafkaSinkConnectorBuilder, KafkaSinkConnectorRetention,
         KafkaSinkFormat, SinkConnectorBuilder, SinkEnvelope,
     },
     sources::{
         encoding::{
-            included_column_desc, AvroEncoding, AvroOcfEncoding, ColumnSpec, CsvEncoding,
+            included_column_desc, AvroEncoding, AvroOcfEncoding, ColumnSpec, CsvEncoding, CsvTrim,
             DataEncoding, ProtobufEncoding, RegexEncoding, SourceDataEncoding,
         },
-        provide_default_metadata, DebeziumDedupProjection, DebeziumEnvelope, DebeziumMode,
-        DebeziumSourceProjection, ExternalSourceConnector, FileSourceConnector, IncludedColumnPos,
+        provide_default_metadata, Compression as SourceCompression, DebeziumDedupProjection,
+        DebeziumEnvelope, DebeziumMode, DebeziumSourceProjection, DecodeErrorPolicy,
+        ExternalSourceConnector, FileSourceConnector, IncludedColumnPos, KafkaDeadletter,
         KafkaSourceConnector, KeyEnvelope, KinesisSourceConnector, PostgresSourceConnector,
         PubNubSourceConnector, S3SourceConnector, SourceConnector, SourceEnvelope, Timeline,
-        UnplannedSourceEnvelope, UpsertStyle,
+        UnplannedSourceEnvelope, UpsertEnvelope, UpsertStyle,
     },
 };
 use mz_expr::GlobalId;
 use mz_interchange::avro::{self, AvroSchemaGenerator};
 use mz_interchange::envelopes;
+use mz_kafka_util::KafkaAddrs;
 use mz_ore::collections::CollectionExt;
 use mz_ore::str::StrExt;
-use mz_repr::{strconv, ColumnName, RelationDesc, RelationType, ScalarType};
+use mz_repr::{strconv, ColumnName, Datum, RelationDesc, RelationType, Row, ScalarType};
 use mz_sql_parser::ast::{CsrSeedCompiledOrLegacy, SourceIncludeMetadata};
 
 use crate::ast::display::AstDisplay;
 use crate::ast::{
-    AlterIndexAction, AlterIndexStatement, AlterObjectRenameStatement, AvroSchema, ColumnOption,
-    Compression, CreateDatabaseStatement, CreateIndexStatement, CreateRoleOption,
-    CreateRoleStatement, CreateSchemaStatement, CreateSinkConnector, CreateSinkStatement,
-    CreateSourceConnector, CreateSourceFormat, CreateSourceStatement, CreateTableStatement,
-    CreateTypeAs, CreateTypeStatement, CreateViewStatement, CreateViewsDefinitions,
-    CreateViewsStatement, CsrConnectorAvro, CsrConnectorProto, CsrSeedCompiled, CsvColumns,
-    DbzMode, DropDatabaseStatement, DropObjectsStatement, Envelope, Expr, Format, Ident,
-    IfExistsBehavior, KafkaConsistency, KeyConstraint, ObjectType, ProtobufSchema, Raw,
-    SourceIncludeMetadataType, SqlOption, Statement, TableConstraint, UnresolvedObjectName, Value,
-    ViewDefinition, WithOption,
+    AlterIndexAction, AlterIndexStatement, AlterObjectRenameStatement, AlterSourceAction,
+    AlterSourceStatement, AlterViewAction, AlterViewStatement, AvroSchema, ColumnOption,
+    Compression, CreateConnectionConnector, CreateConnectionStatement, CreateDatabaseStatement,
+    CreateIndexStatement, CreateRoleOption, CreateRoleStatement, CreateSchemaStatement,
+    CreateSinkConnector, CreateSinkStatement, CreateSourceConnector, CreateSourceFormat,
+    CreateSourceStatement, CreateTableStatement, CreateTypeAs, CreateTypeStatement,
+    CreateViewStatement, CreateViewsDefinitions, CreateViewsStatement, CsrConnectorAvro,
+    CsrConnectorProto, CsrSeedCompiled, CsvColumns, DbzMode, DescribeCreateSinkStatement,
+    DropDatabaseStatement, DropObjectsStatement, Envelope, ExplainEncodingStatement, Expr, Format,
+    Ident, IfExistsBehavior, KafkaConnection, KafkaConsistency, KeyConstraint, ObjectType,
+    ProtobufSchema, Raw, SourceIncludeMetadataType, SqlOption, Statement, TableConstraint,
+    UnresolvedObjectName, UpsertValueDecodingErrors, Value, ViewDefinition, WithOption,
+    WithOptionValue,
 };
 use crate::catalog::{CatalogItem, CatalogItemType, CatalogType, CatalogTypeDetails};
 use crate::kafka_util;
@@ -75,11 +81,12 @@ use crate::plan::query::QueryLifetime;
 use crate::plan::statement::{StatementContext, StatementDesc};
 use crate::plan::{
     plan_utils, query, AlterIndexEnablePlan, AlterIndexResetOptionsPlan, AlterIndexSetOptionsPlan,
-    AlterItemRenamePlan, AlterNoopPlan, CreateDatabasePlan, CreateIndexPlan, CreateRolePlan,
-    CreateSchemaPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan,
-    CreateViewPlan, CreateViewsPlan, DropDatabasePlan, DropItemsPlan, DropRolesPlan,
-    DropSchemaPlan, HirRelationExpr, Index, IndexOption, IndexOptionName, Params, Plan, Sink,
-    Source, Table, Type, View,
+    AlterItemRenamePlan, AlterNoopPlan, AlterSourceRenameColumnPlan, AlterViewResetOptionsPlan,
+    AlterViewSetOptionsPlan, Connection, CreateConnectionPlan, CreateDatabasePlan, CreateIndexPlan,
+    CreateRolePlan, CreateSchemaPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan,
+    CreateTypePlan, CreateViewPlan, CreateViewsPlan, DropDatabasePlan, DropItemsPlan,
+    DropRolesPlan, DropSchemaPlan, HirRelationExpr, Index, IndexOption, IndexOptionName, Params,
+    Plan, PlanNotice, SendRowsPlan, Sink, Source, Table, Type, View,
 };
 use crate::pure::Schema;
 
@@ -176,9 +183,27 @@ pub fn plan_create_table(
     let mut depends_on = Vec::new();
     let mut keys = Vec::new();
 
-    for (i, c) in columns.into_iter().enumerate() {
+    let mut scalar_types = Vec::with_capacity(columns.len());
+    for c in columns {
         let (aug_data_type, ids) = resolve_names_data_type(scx, c.data_type.clone())?;
-        let ty = query::scalar_type_from_sql(scx, &aug_data_type)?;
+        scalar_types.push(query::scalar_type_from_sql(scx, &aug_data_type)?);
+        depends_on.extend(ids);
+    }
+
+    // Default expressions are planned against the table's own columns so that
+    // references to them can be detected after planning.
+    let table_name = normalize::unresolved_object_name(name.clone())?;
+    let default_desc = RelationDesc::new(
+        RelationType::new(
+            scalar_types
+                .iter()
+                .map(|ty| ty.clone().nullable(true))
+                .collect(),
+        ),
+        names.iter().cloned(),
+    );
+
+    for (i, (c, ty)) in columns.into_iter().zip(scalar_types).enumerate() {
         let mut nullable = true;
         let mut default = Expr::null();
         for option in &c.options {
@@ -187,7 +212,22 @@ pub fn plan_create_table(
                 ColumnOption::Default(expr) => {
                     // Ensure expression can be planned and yields the correct
                     // type.
-                    let (_, expr_depends_on) = query::plan_default_expr(scx, expr, &ty)?;
+                    let (hir, expr_depends_on) = query::plan_column_default_expr(
+                        scx,
+                        expr,
+                        &ty,
+                        &table_name,
+                        &default_desc,
+                    )?;
+                    let mut references_column = false;
+                    hir.visit_columns(0, &mut |_, _| references_column = true);
+                    if references_column {
+                        bail!(
+                            "column default for {} may not reference other columns; \
+                            use a generated column",
+                            names[i].as_str().quoted()
+                        );
+                    }
                     depends_on.extend(expr_depends_on);
                     default = expr.clone();
                 }
@@ -204,7 +244,6 @@ pub fn plan_create_table(
         }
         column_types.push(ty.nullable(nullable));
         defaults.push(default);
-        depends_on.extend(ids);
     }
 
     for constraint in constraints {
@@ -297,6 +336,8 @@ pub fn plan_create_source(
         include_metadata,
     } = &stmt;
 
+    validate_envelope_format_compatibility(envelope, format, connector, include_metadata)?;
+
     let with_options_original = with_options;
     let mut with_options = normalize::options(with_options);
 
@@ -314,9 +355,24 @@ pub fn plan_create_source(
         bail_unsupported!("INCLUDE metadata with non-Kafka sources");
     }
 
+    let mut depends_on = vec![];
     let (external_connector, encoding) = match connector {
-        CreateSourceConnector::Kafka { broker, topic, .. } => {
-            let config_options = kafka_util::extract_config(&mut with_options)?;
+        CreateSourceConnector::Kafka {
+            connection, topic, ..
+        } => {
+            let (addrs, config_options) = match connection {
+                KafkaConnection::Inline { broker } => (
+                    broker.parse()?,
+                    kafka_util::extract_config(&mut with_options)?,
+                ),
+                KafkaConnection::Reference { connection } => {
+                    let (id, connector) = kafka_util::resolve_connection(scx.catalog, connection)?;
+                    depends_on.push(id);
+                    let config_options =
+                        kafka_util::extract_config_with_connection(&mut with_options, &connector)?;
+                    (connector.addrs, config_options)
+                }
+            };
 
             let group_id_prefix = match with_options.remove("group_id_prefix") {
                 None => None,
@@ -350,10 +406,35 @@ pub fn plan_create_source(
                 Some(v) => bail!("invalid start_offset value: {}", v),
             }
 
+            // The partition count itself is checked against the topic during
+            // purification.
+            match with_options.remove("expected_partition_count") {
+                None => (),
+                Some(Value::Number(n)) if n.parse::<i32>().map_or(false, |n| n > 0) => (),
+                Some(_) => bail!("expected_partition_count must be a positive integer"),
+            }
+
+            let on_decode_error =
+                plan_kafka_on_decode_error(&mut with_options, &addrs, topic, &config_options)?;
+
+            let value_decompression = match with_options.remove("value_decompression") {
+                None => SourceCompression::None,
+                Some(Value::String(s)) => match s.to_lowercase().as_str() {
+                    "none" => SourceCompression::None,
+                    "gzip" => SourceCompression::Gzip,
+                    _ => bail!("value_decompression must be either 'none' or 'gzip'"),
+                },
+                Some(_) => bail!("value_decompression must be a string"),
+            };
+            if value_decompression != SourceCompression::None && matches!(envelope, Envelope::CdcV2)
+            {
+                bail!("value_decompression is not supported with ENVELOPE MATERIALIZE");
+            }
+
             let encoding = get_encoding(format, envelope, with_options_original)?;
 
             let mut connector = KafkaSourceConnector {
-                addrs: broker.parse()?,
+                addrs,
                 topic: topic.clone(),
                 config_options,
                 start_offsets,
@@ -363,6 +444,8 @@ pub fn plan_create_source(
                 include_partition: None,
                 include_topic: None,
                 include_offset: None,
+                on_decode_error,
+                value_decompression,
             };
 
             let unwrap_name = |alias: Option<Ident>, default, pos| {
@@ -442,12 +525,13 @@ pub fn plan_create_source(
                 Some(Value::Boolean(b)) => b,
                 Some(_) => bail!("tail must be a boolean"),
             };
+            reject_file_rotation_policy(&mut with_options)?;
 
             let connector = ExternalSourceConnector::File(FileSourceConnector {
                 path: path.clone().into(),
                 compression: match compression {
-                    Compression::Gzip => mz_dataflow_types::sources::Compression::Gzip,
-                    Compression::None => mz_dataflow_types::sources::Compression::None,
+                    Compression::Gzip => SourceCompression::Gzip,
+                    Compression::None => SourceCompression::None,
                 },
                 tail,
             });
@@ -492,8 +576,8 @@ pub fn plan_create_source(
                     .transpose()?,
                 aws,
                 compression: match compression {
-                    Compression::Gzip => mz_dataflow_types::sources::Compression::Gzip,
-                    Compression::None => mz_dataflow_types::sources::Compression::None,
+                    Compression::Gzip => SourceCompression::Gzip,
+                    Compression::None => SourceCompression::None,
                 },
             });
             let encoding = get_encoding(format, envelope, with_options_original)?;
@@ -524,10 +608,6 @@ pub fn plan_create_source(
             subscribe_key,
             channel,
         } => {
-            match format {
-                CreateSourceFormat::None | CreateSourceFormat::Bare(Format::Text) => (),
-                _ => bail!("CREATE SOURCE ... PUBNUB must specify FORMAT TEXT"),
-            }
             let connector = ExternalSourceConnector::PubNub(PubNubSourceConnector {
                 subscribe_key: subscribe_key.clone(),
                 channel: channel.clone(),
@@ -540,15 +620,13 @@ pub fn plan_create_source(
                 Some(Value::Boolean(b)) => b,
                 Some(_) => bail!("tail must be a boolean"),
             };
+            reject_file_rotation_policy(&mut with_options)?;
 
             let connector = ExternalSourceConnector::AvroOcf(FileSourceConnector {
                 path: path.clone().into(),
-                compression: mz_dataflow_types::sources::Compression::None,
+                compression: SourceCompression::None,
                 tail,
             });
-            if !matches!(format, CreateSourceFormat::None) {
-                bail!("avro ocf sources cannot specify a format");
-            }
             let reader_schema = match with_options
                 .remove("reader_schema")
                 .expect("purification guarantees presence of reader_schema")
@@ -685,7 +763,7 @@ pub fn plan_create_source(
                 }
             }
         }
-        mz_sql_parser::ast::Envelope::Upsert => {
+        mz_sql_parser::ast::Envelope::Upsert(value_decoding_errors) => {
             if encoding.key_ref().is_none() {
                 bail_unsupported!(format!("upsert requires a key/value format: {:?}", format));
             }
@@ -694,19 +772,17 @@ pub fn plan_create_source(
                 Some(DataEncoding::Avro(_)) => key_envelope.unwrap_or(KeyEnvelope::Flattened),
                 _ => key_envelope.unwrap_or(KeyEnvelope::LegacyUpsert),
             };
-            UnplannedSourceEnvelope::Upsert(UpsertStyle::Default(key_envelope))
+            match value_decoding_errors {
+                UpsertValueDecodingErrors::Fail => {
+                    UnplannedSourceEnvelope::Upsert(UpsertStyle::Default(key_envelope))
+                }
+                UpsertValueDecodingErrors::Inline => {
+                    UnplannedSourceEnvelope::Upsert(UpsertStyle::ValueErrInline { key_envelope })
+                }
+            }
         }
         mz_sql_parser::ast::Envelope::CdcV2 => {
             //TODO check that key envelope is not set
-            if let CreateSourceConnector::AvroOcf { .. } = connector {
-                // TODO[btv] - there is no fundamental reason not to support this eventually,
-                // but OCF goes through a separate pipeline that it hasn't been implemented for.
-                bail_unsupported!("ENVELOPE MATERIALIZE over OCF (Avro files)")
-            }
-            match format {
-                CreateSourceFormat::Bare(Format::Avro(_)) => {}
-                _ => bail_unsupported!("non-Avro-encoded ENVELOPE MATERIALIZE"),
-            }
             UnplannedSourceEnvelope::CdcV2
         }
     };
@@ -718,6 +794,27 @@ pub fn plan_create_source(
     let metadata_desc = included_column_desc(metadata_columns.clone());
     let (envelope, mut desc) = envelope.desc(key_desc, value_desc, metadata_desc)?;
 
+    // Metadata columns requested with INCLUDE must not share a name with a
+    // decoded key or value column, unless column aliases rename them apart.
+    if col_names.is_empty() {
+        for item in include_metadata
+            .iter()
+            .filter(|i| i.ty != SourceIncludeMetadataType::Key)
+        {
+            let name = match &item.alias {
+                Some(alias) => alias.as_str().to_string(),
+                None => item.ty.to_string().to_lowercase(),
+            };
+            if desc.iter_names().filter(|n| n.as_str() == name).count() > 1 {
+                bail!(
+                    "column '{}' from INCLUDE conflicts with payload column; use INCLUDE {} AS ...",
+                    name,
+                    item.ty
+                );
+            }
+        }
+    }
+
     // Append default metadata columns if column aliases were provided but do not include them.
     //
     // This is a confusing hack due to two combined facts:
@@ -754,6 +851,24 @@ pub fn plan_create_source(
         desc = desc.without_keys();
     }
 
+    // Apply the key declared by annotations in the Avro value schema. This
+    // must happen before the columns are renamed, as the annotations refer to
+    // the Avro field names.
+    match with_options.remove("avro_key_annotation") {
+        None => (),
+        Some(Value::String(annotation)) => {
+            if key_constraint.is_some() {
+                bail!("avro_key_annotation cannot be combined with a key constraint");
+            }
+            if ignore_source_keys {
+                bail!("avro_key_annotation cannot be combined with ignore_source_keys");
+            }
+            let key_indices = plan_avro_key_annotation(encoding.value_ref(), &desc, &annotation)?;
+            desc = desc.with_key(key_indices);
+        }
+        Some(_) => bail!("avro_key_annotation must be a string"),
+    }
+
     desc = plan_utils::maybe_rename_columns(format!("source {}", name), desc, &col_names)?;
 
     // Apply user-specified key constraint
@@ -791,6 +906,68 @@ pub fn plan_create_source(
         }
     }
 
+    let timestamp_column = match with_options.remove("timestamp_column") {
+        None => None,
+        Some(Value::String(col)) => Some(plan_timestamp_column(
+            scx,
+            &desc,
+            &col,
+            with_options.remove("timestamp_column_null_policy"),
+        )?),
+        Some(_) => bail!("timestamp_column must be a string"),
+    };
+
+    let max_lateness = match with_options.remove("max_lateness") {
+        None => None,
+        Some(_) if timestamp_column.is_none() => {
+            bail!("max_lateness can only be specified alongside timestamp_column")
+        }
+        // Late rows are dropped as they are decoded. Envelopes that maintain
+        // state over the decoded rows would diverge from the dropped rows.
+        Some(_) if !matches!(envelope, SourceEnvelope::None(_)) => {
+            bail!("max_lateness requires ENVELOPE NONE")
+        }
+        Some(Value::String(s)) => Some(mz_repr::util::parse_duration(&s)?),
+        Some(_) => bail!("max_lateness must be a string"),
+    };
+
+    let max_decode_errors = match with_options.remove("max_decode_errors") {
+        None => None,
+        Some(_)
+            if !matches!(
+                &external_connector,
+                ExternalSourceConnector::Kafka(KafkaSourceConnector {
+                    on_decode_error: DecodeErrorPolicy::Skip | DecodeErrorPolicy::Deadletter(_),
+                    ..
+                })
+            ) =>
+        {
+            bail!("max_decode_errors requires on_decode_error = 'skip' or 'deadletter'")
+        }
+        Some(Value::Number(n)) => Some(
+            n.parse::<u64>()
+                .map_err(|_| anyhow!("max_decode_errors must be a non-negative integer"))?,
+        ),
+        Some(_) => bail!("max_decode_errors must be a non-negative integer"),
+    };
+
+    let max_records_per_second = match with_options.remove("max_records_per_second") {
+        None => None,
+        Some(_)
+            if matches!(
+                &external_connector,
+                ExternalSourceConnector::Postgres(_) | ExternalSourceConnector::PubNub(_)
+            ) =>
+        {
+            bail!("max_records_per_second is not supported for Postgres or PubNub sources")
+        }
+        Some(Value::Number(n)) => match n.parse::<u64>() {
+            Ok(n) if n > 0 => Some(n),
+            _ => bail!("max_records_per_second must be a positive integer"),
+        },
+        Some(_) => bail!("max_records_per_second must be a positive integer"),
+    };
+
     let if_not_exists = *if_not_exists;
     let materialized = *materialized;
     let name = scx.allocate_name(normalize::unresolved_object_name(name.clone())?);
@@ -828,18 +1005,389 @@ pub fn plan_create_source(
             metadata_columns: metadata_column_types,
             ts_frequency,
             timeline,
+            timestamp_column,
+            max_lateness,
+            max_decode_errors,
+            max_records_per_second,
         },
         expr,
         desc,
+        depends_on,
     };
 
+    let mut notices = vec![];
+    if let SourceConnector::External {
+        connector: ExternalSourceConnector::Kafka(_),
+        ..
+    } = &source.connector
+    {
+        notices.extend(kafka_util::take_misspelled_options(&mut with_options)?);
+    }
+
     normalize::ensure_empty_options(&with_options, "CREATE SOURCE")?;
 
+    if materialized {
+        notices.push(PlanNotice::MaterializedSourceDeprecated {
+            source_name: name.clone(),
+        });
+    }
+
     Ok(Plan::CreateSource(CreateSourcePlan {
         name,
         source,
         if_not_exists,
         materialized,
+        notices,
+    }))
+}
+
+pub fn describe_explain_encoding(
+    _: &StatementContext,
+    _: ExplainEncodingStatement<Raw>,
+) -> Result<StatementDesc, anyhow::Error> {
+    Ok(StatementDesc::new(Some(
+        RelationDesc::empty()
+            .with_column("Component", ScalarType::String.nullable(false))
+            .with_column("Name", ScalarType::String.nullable(false))
+            .with_column("Type", ScalarType::String.nullable(true))
+            .with_column("Nullable", ScalarType::Bool.nullable(true)),
+    )))
+}
+
+/// Plans the wrapped `CREATE SOURCE` statement without creating the source,
+/// and reports the encodings, envelope, and metadata columns that determine
+/// the source's columns, followed by the columns themselves.
+pub fn plan_explain_encoding(
+    scx: &StatementContext,
+    ExplainEncodingStatement { stmt }: ExplainEncodingStatement<Raw>,
+) -> Result<Plan, anyhow::Error> {
+    let source = match plan_create_source(scx, stmt)? {
+        Plan::CreateSource(CreateSourcePlan { source, .. }) => source,
+        _ => unreachable!("plan_create_source always returns a CreateSource plan"),
+    };
+    let (encoding, envelope, metadata_columns) = match &source.connector {
+        SourceConnector::External {
+            encoding,
+            envelope,
+            metadata_columns,
+            ..
+        } => (encoding, envelope, metadata_columns),
+        SourceConnector::Local { .. } => bail!("EXPLAIN ENCODING requires an external source"),
+    };
+
+    let (key_encoding, value_encoding) = match encoding {
+        SourceDataEncoding::Single(value) => ("None", value.op_name()),
+        SourceDataEncoding::KeyValue { key, value } => (key.op_name(), value.op_name()),
+    };
+    let (envelope_name, key_envelope) = match envelope {
+        SourceEnvelope::None(key_envelope) => ("None", Some(key_envelope)),
+        SourceEnvelope::Debezium(_) => ("Debezium", None),
+        SourceEnvelope::Upsert(UpsertEnvelope {
+            style: UpsertStyle::Default(key_envelope),
+            ..
+        }) => ("Upsert", Some(key_envelope)),
+        SourceEnvelope::Upsert(UpsertEnvelope {
+            style: UpsertStyle::ValueErrInline { key_envelope },
+            ..
+        }) => ("Upsert (value decoding errors inline)", Some(key_envelope)),
+        SourceEnvelope::Upsert(UpsertEnvelope {
+            style: UpsertStyle::Debezium { .. },
+            ..
+        }) => ("Debezium Upsert", None),
+        SourceEnvelope::CdcV2 => ("Materialize", None),
+    };
+
+    let mut components = vec![
+        ("key encoding", key_encoding.to_string()),
+        ("value encoding", value_encoding.to_string()),
+        ("envelope", envelope_name.to_string()),
+    ];
+    if let Some(key_envelope) = key_envelope {
+        let key_envelope = match key_envelope {
+            KeyEnvelope::None => "None".to_string(),
+            KeyEnvelope::Flattened => "Flattened".to_string(),
+            KeyEnvelope::LegacyUpsert => "LegacyUpsert".to_string(),
+            KeyEnvelope::Named(name) => format!("Named ({})", name),
+            KeyEnvelope::Record(name) => format!("Record ({})", name),
+        };
+        components.push(("key envelope", key_envelope));
+    }
+    for metadata_column in metadata_columns {
+        components.push(("metadata column", metadata_column.to_string()));
+    }
+
+    let mut rows = components
+        .iter()
+        .map(|(component, name)| {
+            Row::pack_slice(&[
+                Datum::String(component),
+                Datum::String(name),
+                Datum::Null,
+                Datum::Null,
+            ])
+        })
+        .collect::<Vec<_>>();
+    for (name, typ) in source.desc.iter() {
+        rows.push(Row::pack_slice(&[
+            Datum::String("column"),
+            Datum::String(name.as_str()),
+            Datum::String(&scx.humanize_scalar_type(&typ.scalar_type)),
+            Datum::from(typ.nullable),
+        ]));
+    }
+    Ok(Plan::SendRows(SendRowsPlan { rows }))
+}
+
+/// Checks that `envelope` and `format` can be used together over `connector`,
+/// along with the requested `include_metadata`.
+///
+/// All incompatibilities are reported at once, so that users assembling a new
+/// source need not discover them one at a time. A combination that is merely
+/// not yet supported is reported as such when it is the only problem.
+fn validate_envelope_format_compatibility(
+    envelope: &Envelope,
+    format: &CreateSourceFormat<Raw>,
+    connector: &CreateSourceConnector,
+    include_metadata: &[SourceIncludeMetadata],
+) -> Result<(), anyhow::Error> {
+    let mut unsupported = None;
+    let mut problems = vec![];
+    match envelope {
+        Envelope::CdcV2 => {
+            if let CreateSourceConnector::AvroOcf { .. } = connector {
+                // TODO[btv] - there is no fundamental reason not to support this eventually,
+                // but OCF goes through a separate pipeline that it hasn't been implemented for.
+                unsupported = Some("ENVELOPE MATERIALIZE over OCF (Avro files)");
+            } else if !matches!(format, CreateSourceFormat::Bare(Format::Avro(_))) {
+                unsupported = Some("non-Avro-encoded ENVELOPE MATERIALIZE");
+            }
+            // CDCv2 updates carry their own timestamps and diffs, so there is
+            // nothing sensible to do with metadata columns.
+            if !include_metadata.is_empty() {
+                problems.push(
+                    "INCLUDE metadata is not supported with ENVELOPE MATERIALIZE.".to_string(),
+                );
+            }
+        }
+        Envelope::Upsert(_) | Envelope::Debezium(DbzMode::Upsert) => {
+            // A schema registry may provide the key schema, which is only known
+            // once the encoding is determined.
+            let may_have_key = matches!(
+                format,
+                CreateSourceFormat::KeyValue { .. }
+                    | CreateSourceFormat::Bare(Format::Avro(AvroSchema::Csr { .. }))
+                    | CreateSourceFormat::Bare(Format::Protobuf(ProtobufSchema::Csr { .. }))
+            );
+            if !may_have_key {
+                problems.push(
+                    "ENVELOPE [DEBEZIUM] UPSERT requires that KEY FORMAT be specified".to_string(),
+                );
+            }
+        }
+        Envelope::None | Envelope::Debezium(_) => {}
+    }
+    match connector {
+        CreateSourceConnector::PubNub { .. } => {
+            if !matches!(
+                format,
+                CreateSourceFormat::None | CreateSourceFormat::Bare(Format::Text)
+            ) {
+                problems.push("CREATE SOURCE ... PUBNUB must specify FORMAT TEXT".to_string());
+            }
+        }
+        CreateSourceConnector::AvroOcf { .. } => {
+            if !matches!(format, CreateSourceFormat::None) {
+                problems.push("avro ocf sources cannot specify a format".to_string());
+            }
+        }
+        _ => {}
+    }
+    if let Some(feature) = unsupported {
+        if problems.is_empty() {
+            bail_unsupported!(feature);
+        }
+        let unsupported = PlanError::Unsupported {
+            feature: feature.to_string(),
+            issue_no: None,
+        };
+        problems.insert(0, unsupported.to_string());
+    }
+    if !problems.is_empty() {
+        bail!("{}", problems.join("; "));
+    }
+    Ok(())
+}
+
+/// Resolves the `timestamp_column` option of a source to the index of the
+/// column from which the source's timestamps are derived.
+///
+/// The column must be of type `timestamp` or `timestamp with time zone`. A
+/// nullable column additionally requires `timestamp_column_null_policy =
+/// 'ingestion_time'`, which assigns rows with a null timestamp the time at
+/// which they were ingested.
+fn plan_timestamp_column(
+    scx: &StatementContext,
+    desc: &RelationDesc,
+    col: &str,
+    null_policy: Option<Value>,
+) -> Result<usize, anyhow::Error> {
+    let col = ColumnName::from(col);
+    let (idx, typ) = desc
+        .get_by_name(&col)
+        .ok_or_else(|| anyhow!("timestamp_column {} does not exist", col.as_str().quoted()))?;
+    if desc.get_unambiguous_name(idx).is_none() {
+        bail!("timestamp_column {} is ambiguous", col.as_str().quoted());
+    }
+    if !matches!(
+        typ.scalar_type,
+        ScalarType::Timestamp | ScalarType::TimestampTz
+    ) {
+        bail!(
+            "timestamp_column {} must have type timestamp or timestamp with time zone, not {}",
+            col.as_str().quoted(),
+            scx.humanize_scalar_type(&typ.scalar_type)
+        );
+    }
+    match null_policy {
+        None if typ.nullable => bail!(
+            "timestamp_column {} is nullable; \
+             specify timestamp_column_null_policy = 'ingestion_time'",
+            col.as_str().quoted()
+        ),
+        None => (),
+        Some(Value::String(s)) if s == "ingestion_time" => (),
+        Some(_) => bail!("timestamp_column_null_policy must be 'ingestion_time'"),
+    }
+    Ok(idx)
+}
+
+/// Resolves the `avro_key_annotation` option of a source to the indices of the
+/// columns that form the source's key.
+///
+/// The key consists of the top-level fields of the Avro value schema whose
+/// `annotation` property is `true`, in schema order. Each such field must be
+/// a non-nullable column of the source.
+fn plan_avro_key_annotation(
+    encoding: &DataEncoding,
+    desc: &RelationDesc,
+    annotation: &str,
+) -> Result<Vec<usize>, anyhow::Error> {
+    let schema = match encoding {
+        DataEncoding::Avro(encoding) => encoding.decoding_schema(),
+        _ => bail!("avro_key_annotation requires FORMAT AVRO"),
+    };
+    let schema: serde_json::Value = serde_json::from_str(schema)?;
+    let fields = match schema.get("fields").and_then(|fields| fields.as_array()) {
+        Some(fields) => fields,
+        None => bail!("avro_key_annotation requires a value schema of type record"),
+    };
+    let mut key_indices = vec![];
+    for field in fields {
+        if field.get(annotation) != Some(&serde_json::Value::Bool(true)) {
+            continue;
+        }
+        let name = match field.get("name").and_then(|name| name.as_str()) {
+            Some(name) => ColumnName::from(name),
+            None => bail!("avro_key_annotation: field without a name"),
+        };
+        let (idx, typ) = desc.get_by_name(&name).ok_or_else(|| {
+            anyhow!(
+                "field {} annotated with {} is not a column of the source",
+                name.as_str().quoted(),
+                annotation.quoted()
+            )
+        })?;
+        if typ.nullable {
+            bail!(
+                "field {} annotated with {} is nullable; key fields must not be nullable",
+                name.as_str().quoted(),
+                annotation.quoted()
+            );
+        }
+        key_indices.push(idx);
+    }
+    if key_indices.is_empty() {
+        bail!(
+            "avro_key_annotation {} does not annotate any field of the value schema",
+            annotation.quoted()
+        );
+    }
+    Ok(key_indices)
+}
+
+/// Plans the `on_decode_error` option of a Kafka source.
+///
+/// By default, or with `on_decode_error = 'error'`, records that fail to
+/// decode surface as decode errors. With `on_decode_error = 'skip'` they are
+/// dropped, and with `on_decode_error = 'deadletter'` they are published to
+/// `deadletter_topic`. The deadletter topic reuses the source's brokers and
+/// config options unless `deadletter_broker` names another cluster, in which
+/// case the deadletter producer is configured only by the Kafka options given
+/// with a `deadletter_` prefix, e.g. `deadletter_security_protocol`.
+fn plan_kafka_on_decode_error(
+    with_options: &mut BTreeMap<String, Value>,
+    addrs: &KafkaAddrs,
+    topic: &str,
+    config_options: &BTreeMap<String, String>,
+) -> Result<DecodeErrorPolicy, anyhow::Error> {
+    match with_options.remove("on_decode_error") {
+        None => return Ok(DecodeErrorPolicy::Error),
+        Some(Value::String(s)) if s == "error" => return Ok(DecodeErrorPolicy::Error),
+        Some(Value::String(s)) if s == "skip" => return Ok(DecodeErrorPolicy::Skip),
+        Some(Value::String(s)) if s == "deadletter" => (),
+        Some(_) => bail!("on_decode_error must be one of 'error', 'skip' or 'deadletter'"),
+    }
+
+    let deadletter_topic = match with_options.remove("deadletter_topic") {
+        Some(Value::String(s)) => s,
+        Some(_) => bail!("deadletter_topic must be a string"),
+        None => bail!("on_decode_error = 'deadletter' requires deadletter_topic"),
+    };
+    kafka_util::validate_topic_name(&deadletter_topic)?;
+    let deadletter_broker = with_options.remove("deadletter_broker");
+
+    let deadletter_keys: Vec<_> = with_options
+        .keys()
+        .filter(|key| key.starts_with("deadletter_"))
+        .cloned()
+        .collect();
+    let mut deadletter_options = BTreeMap::new();
+    for key in deadletter_keys {
+        let value = with_options.remove(&key).expect("key is present");
+        deadletter_options.insert(key["deadletter_".len()..].to_string(), value);
+    }
+
+    let (deadletter_addrs, deadletter_config_options) = match deadletter_broker {
+        Some(Value::String(s)) => {
+            let config_options = kafka_util::extract_config(&mut deadletter_options)?;
+            if !deadletter_options.is_empty() {
+                bail!(
+                    "unexpected parameters for deadletter topic: {}",
+                    deadletter_options
+                        .keys()
+                        .map(|key| format!("deadletter_{}", key))
+                        .collect::<Vec<_>>()
+                        .join(",")
+                )
+            }
+            (s.parse()?, config_options)
+        }
+        Some(_) => bail!("deadletter_broker must be a string"),
+        None => {
+            if let Some(key) = deadletter_options.keys().next() {
+                bail!("deadletter_{} requires deadletter_broker", key);
+            }
+            if deadletter_topic == topic {
+                bail!("deadletter_topic must differ from the source's topic");
+            }
+            (addrs.clone(), config_options.clone())
+        }
+    };
+
+    Ok(DecodeErrorPolicy::Deadletter(KafkaDeadletter {
+        addrs: deadletter_addrs,
+        topic: deadletter_topic,
+        config_options: deadletter_config_options,
     }))
 }
 
@@ -986,6 +1534,17 @@ fn typecheck_debezium_dedup(
     })
 }
 
+/// Rejects the `on_rotate` option of file sources, as the file reader cannot
+/// yet tell that the file at its path has been replaced.
+fn reject_file_rotation_policy(
+    with_options: &mut BTreeMap<String, Value>,
+) -> Result<(), anyhow::Error> {
+    if with_options.remove("on_rotate").is_some() {
+        bail_unsupported!("on_rotate");
+    }
+    Ok(())
+}
+
 fn get_encoding<T: mz_sql_parser::ast::AstInfo>(
     format: &CreateSourceFormat<Raw>,
     envelope: &Envelope,
@@ -1009,7 +1568,7 @@ fn get_encoding<T: mz_sql_parser::ast::AstInfo>(
 
     let requires_keyvalue = matches!(
         envelope,
-        Envelope::Debezium(DbzMode::Upsert) | Envelope::Upsert
+        Envelope::Debezium(DbzMode::Upsert) | Envelope::Upsert(_)
     );
     let is_keyvalue = matches!(encoding, SourceDataEncoding::KeyValue { .. });
     if requires_keyvalue && !is_keyvalue {
@@ -1019,6 +1578,58 @@ fn get_encoding<T: mz_sql_parser::ast::AstInfo>(
     Ok(encoding)
 }
 
+/// Plans the `confluent_wire_format` option of an inline Avro schema, which
+/// is either a boolean or `'auto'`.
+fn plan_confluent_wire_format(
+    mut with_options: Vec<WithOption>,
+) -> Result<avro::ConfluentWireFormat, anyhow::Error> {
+    let confluent_wire_format = match with_options
+        .iter()
+        .position(|opt| opt.key.as_str() == "confluent_wire_format")
+    {
+        // The wire format has always been the default, for compatibility.
+        None => avro::ConfluentWireFormat::Enabled,
+        Some(pos) => match with_options.swap_remove(pos).value {
+            None | Some(WithOptionValue::Value(Value::Boolean(true))) => {
+                avro::ConfluentWireFormat::Enabled
+            }
+            Some(WithOptionValue::Value(Value::Boolean(false))) => {
+                avro::ConfluentWireFormat::Disabled
+            }
+            Some(WithOptionValue::Value(Value::String(s))) if s == "auto" => {
+                avro::ConfluentWireFormat::Auto
+            }
+            Some(_) => bail!("confluent_wire_format must be a boolean or 'auto'"),
+        },
+    };
+    if !with_options.is_empty() {
+        bail!(
+            "unexpected parameters for inline Avro schema: {}",
+            with_options.iter().map(|opt| opt.key.as_str()).join(",")
+        );
+    }
+    Ok(confluent_wire_format)
+}
+
+/// Checks that `reader_schema` is a valid Avro schema into which messages
+/// written with `writer_schema` can be resolved.
+fn validate_avro_reader_schema(
+    writer_schema: &str,
+    reader_schema: &str,
+) -> Result<(), anyhow::Error> {
+    let writer = avro::parse_schema(writer_schema)
+        .map_err(|e| anyhow!("invalid registry value schema: {}", e))?;
+    let reader =
+        avro::parse_schema(reader_schema).map_err(|e| anyhow!("invalid READER SCHEMA: {}", e))?;
+    mz_avro::schema::resolve_schemas(&writer, &reader).map_err(|e| {
+        anyhow!(
+            "READER SCHEMA is not compatible with the registry value schema: {}",
+            e
+        )
+    })?;
+    Ok(())
+}
+
 fn get_encoding_inner<T: mz_sql_parser::ast::AstInfo>(
     format: &Format<Raw>,
     with_options: &Vec<SqlOption<T>>,
@@ -1027,33 +1638,29 @@ fn get_encoding_inner<T: mz_sql_parser::ast::AstInfo>(
     Ok(SourceDataEncoding::Single(match format {
         Format::Bytes => DataEncoding::Bytes,
         Format::Avro(schema) => {
-            let Schema {
-                key_schema,
-                value_schema,
-                schema_registry_config,
-                confluent_wire_format,
-            } = match schema {
+            let (
+                Schema {
+                    key_schema,
+                    value_schema,
+                    schema_registry_config,
+                    confluent_wire_format,
+                },
+                reader_schema,
+            ) = match schema {
                 // TODO(jldlaughlin): we need a way to pass in primary key information
                 // when building a source from a string or file.
                 AvroSchema::InlineSchema {
                     schema: mz_sql_parser::ast::Schema::Inline(schema),
                     with_options,
-                } => {
-                    with_options! {
-                        struct ConfluentMagic {
-                            confluent_wire_format: bool,
-                        }
-                    }
-
+                } => (
                     Schema {
                         key_schema: None,
                         value_schema: schema.clone(),
                         schema_registry_config: None,
-                        confluent_wire_format: ConfluentMagic::try_from(with_options.clone())?
-                            .confluent_wire_format
-                            .unwrap_or(true),
-                    }
-                }
+                        confluent_wire_format: plan_confluent_wire_format(with_options.clone())?,
+                    },
+                    None,
+                ),
                 AvroSchema::InlineSchema {
                     schema: mz_sql_parser::ast::Schema::File(_),
                     ..
@@ -1064,11 +1671,16 @@ fn get_encoding_inner<T: mz_sql_parser::ast::AstInfo>(
                     csr_connector:
                         CsrConnectorAvro {
                             url,
+                            reader_schema,
                             seed,
                             with_options: ccsr_options,
                         },
                 } => {
                     let mut ccsr_with_options = normalize::options(&ccsr_options);
+                    // The subjects were already resolved into the seed during
+                    // purification.
+                    kafka_util::extract_subject_name_strategy(&mut ccsr_with_options, "key")?;
+                    kafka_util::extract_subject_name_strategy(&mut ccsr_with_options, "value")?;
                     let ccsr_config = kafka_util::generate_ccsr_client_config(
                         url.parse()?,
                         &kafka_util::extract_config(&mut normalize::options(with_options))?,
@@ -1079,12 +1691,18 @@ fn get_encoding_inner<T: mz_sql_parser::ast::AstInfo>(
                         "CONFLUENT SCHEMA REGISTRY",
                     )?;
                     if let Some(seed) = seed {
-                        Schema {
-                            key_schema: seed.key_schema.clone(),
-                            value_schema: seed.value_schema.clone(),
-                            schema_registry_config: Some(ccsr_config),
-                            confluent_wire_format: true,
+                        if let Some(reader_schema) = reader_schema {
+                            validate_avro_reader_schema(&seed.value_schema, reader_schema)?;
                         }
+                        (
+                            Schema {
+                                key_schema: seed.key_schema.clone(),
+                                value_schema: seed.value_schema.clone(),
+                                schema_registry_config: Some(ccsr_config),
+                                confluent_wire_format: avro::ConfluentWireFormat::Enabled,
+                            },
+                            reader_schema.clone(),
+                        )
                     } else {
                         unreachable!("CSR seed resolution should already have been called: Avro")
                     }
@@ -1095,11 +1713,13 @@ fn get_encoding_inner<T: mz_sql_parser::ast::AstInfo>(
                 return Ok(SourceDataEncoding::KeyValue {
                     key: DataEncoding::Avro(AvroEncoding {
                         schema: key_schema,
+                        reader_schema: None,
                         schema_registry_config: schema_registry_config.clone(),
                         confluent_wire_format,
                     }),
                     value: DataEncoding::Avro(AvroEncoding {
                         schema: value_schema,
+                        reader_schema,
                         schema_registry_config,
                         confluent_wire_format,
                     }),
@@ -1107,6 +1727,7 @@ fn get_encoding_inner<T: mz_sql_parser::ast::AstInfo>(
             } else {
                 DataEncoding::Avro(AvroEncoding {
                     schema: value_schema,
+                    reader_schema,
                     schema_registry_config,
                     confluent_wire_format,
                 })
@@ -1181,8 +1802,50 @@ fn get_encoding_inner<T: mz_sql_parser::ast::AstInfo>(
                 regex: mz_repr::adt::regex::Regex(regex),
             })
         }
-        Format::Csv { columns, delimiter } => {
+        Format::Csv {
+            columns,
+            delimiter,
+            with_options,
+        } => {
+            with_options! {
+                struct CsvOptions {
+                    null_string: String,
+                    infer_columns: bool,
+                    trim: String,
+                }
+            }
+
+            let CsvOptions {
+                null_string,
+                infer_columns,
+                trim,
+            } = CsvOptions::try_from(with_options.clone())?;
+            let infer_columns = infer_columns.unwrap_or(false);
+            let trim = match trim.as_deref() {
+                None | Some("none") => CsvTrim::None,
+                Some("leading") => CsvTrim::Leading,
+                Some("trailing") => CsvTrim::Trailing,
+                Some("both") => CsvTrim::Both,
+                Some(_) => bail!("CSV trim must be one of 'none', 'leading', 'trailing' or 'both'"),
+            };
+            let delimiter = match *delimiter as u32 {
+                0..=127 => *delimiter as u8,
+                _ => bail!("CSV delimiter must be an ASCII character"),
+            };
+            if let Some(null_string) = &null_string {
+                // Quoted fields are unquoted before we see them, so a sentinel
+                // containing a quote could never be told apart from data.
+                if null_string.contains('"') {
+                    bail!("CSV null_string cannot contain the quote character '\"'");
+                }
+                if null_string.as_bytes().contains(&delimiter) {
+                    bail!("CSV null_string cannot contain the delimiter");
+                }
+            }
             let columns = match columns {
+                CsvColumns::Header { .. } | CsvColumns::Count(_) if infer_columns => {
+                    bail!("CSV infer_columns cannot be combined with WITH HEADER or WITH n COLUMNS")
+                }
                 CsvColumns::Header { names } => {
                     if names.is_empty() {
                         bail!("[internal error] column spec should get names in purify")
@@ -1192,13 +1855,18 @@ fn get_encoding_inner<T: mz_sql_parser::ast::AstInfo>(
                     }
                 }
                 CsvColumns::Count(n) => ColumnSpec::Count(*n),
+                CsvColumns::Infer if infer_columns => {
+                    bail!("[internal error] column count should be inferred in purify")
+                }
+                CsvColumns::Infer => bail!(
+                    "CSV format requires WITH HEADER, WITH n COLUMNS, or WITH (infer_columns = true)"
+                ),
             };
             DataEncoding::Csv(CsvEncoding {
                 columns,
-                delimiter: match *delimiter as u32 {
-                    0..=127 => *delimiter as u8,
-                    _ => bail!("CSV delimiter must be an ASCII character"),
-                },
+                delimiter,
+                null_string,
+                trim,
             })
         }
         Format::Json => bail_unsupported!("JSON sources"),
@@ -1218,28 +1886,56 @@ fn get_key_envelope(
     if matches!(envelope, Envelope::Debezium { .. }) && key_definition.is_some() {
         bail!("Cannot use INCLUDE KEY with ENVELOPE DEBEZIUM: Debezium values include all keys.");
     }
+    if let Some(item) = included_items
+        .iter()
+        .find(|i| i.ty != SourceIncludeMetadataType::Key && !i.options.is_empty())
+    {
+        bail!("INCLUDE {} does not support options", item.ty);
+    }
+
+    with_options! {
+        struct IncludeKeyOptions {
+            as_record: bool,
+        }
+    }
+
+    let is_composite = |key: &DataEncoding| match key {
+        DataEncoding::AvroOcf { .. } | DataEncoding::Postgres => {
+            bail!("{} sources cannot use INCLUDE KEY", key.op_name())
+        }
+        DataEncoding::Bytes | DataEncoding::Text => Ok(false),
+        DataEncoding::Avro(_)
+        | DataEncoding::Csv(_)
+        | DataEncoding::Protobuf(_)
+        | DataEncoding::Regex { .. } => Ok(true),
+    };
+
     if let Some(kd) = key_definition {
+        let as_record = IncludeKeyOptions::try_from(kd.options.clone())
+            .map_err(|e| anyhow!("invalid INCLUDE KEY options: {}", e))?
+            .as_record
+            .unwrap_or(false);
         Ok(Some(match (&kd.alias, encoding) {
+            (Some(name), SourceDataEncoding::KeyValue { key, .. }) if as_record => {
+                if !is_composite(key)? {
+                    bail!(
+                        "INCLUDE KEY AS {} (as_record = true) requires a composite key \
+                        encoding, but the key format is {}",
+                        name,
+                        key.op_name()
+                    );
+                }
+                KeyEnvelope::Record(name.as_str().to_string())
+            }
             (Some(name), SourceDataEncoding::KeyValue { .. }) => {
                 KeyEnvelope::Named(name.as_str().to_string())
             }
-            (None, _) if matches!(envelope, Envelope::Upsert { .. }) => KeyEnvelope::LegacyUpsert,
+            (None, _) if matches!(envelope, Envelope::Upsert(_)) => KeyEnvelope::LegacyUpsert,
             (None, SourceDataEncoding::KeyValue { key, .. }) => {
                 // If the key is requested but comes from an unnamed type then it gets the name "key"
                 //
                 // Otherwise it gets the names of the columns in the type
-                let is_composite = match key {
-                    DataEncoding::AvroOcf { .. } | DataEncoding::Postgres => {
-                        bail!("{} sources cannot use INCLUDE KEY", key.op_name())
-                    }
-                    DataEncoding::Bytes | DataEncoding::Text => false,
-                    DataEncoding::Avro(_)
-                    | DataEncoding::Csv(_)
-                    | DataEncoding::Protobuf(_)
-                    | DataEncoding::Regex { .. } => true,
-                };
-
-                if is_composite {
+                if is_composite(key)? {
                     KeyEnvelope::Flattened
                 } else {
                     KeyEnvelope::Named("key".to_string())
@@ -1286,6 +1982,7 @@ pub fn plan_view(
     let ViewDefinition {
         name,
         columns,
+        column_types,
         query,
         with_options,
     } = def;
@@ -1297,13 +1994,12 @@ pub fn plan_view(
         mut expr,
         mut desc,
         finishing,
-        depends_on,
+        mut depends_on,
     } = query::plan_root_query(scx, query.clone(), QueryLifetime::Static)?;
 
     expr.bind_parameters(&params)?;
     //TODO: materialize#724 - persist finishing information with the view?
     expr.finish(finishing);
-    let relation_expr = expr.optimize_and_lower(&scx.into());
 
     let name = if temporary {
         scx.allocate_temporary_name(normalize::unresolved_object_name(name.to_owned())?)
@@ -1312,6 +2008,27 @@ pub fn plan_view(
     };
 
     desc = plan_utils::maybe_rename_columns(format!("view {}", name), desc, &columns)?;
+
+    // Install casts to any column types declared in the view definition.
+    if !column_types.is_empty() {
+        let mut target_types = Vec::with_capacity(column_types.len());
+        for data_type in column_types.iter() {
+            target_types.push(match data_type {
+                None => None,
+                Some(data_type) => {
+                    let (aug_data_type, ids) = resolve_names_data_type(scx, data_type.clone())?;
+                    depends_on.extend(ids);
+                    Some(query::scalar_type_from_sql(scx, &aug_data_type)?)
+                }
+            });
+        }
+        let (cast_expr, cast_desc) =
+            query::plan_view_column_casts(scx, expr, &desc, &target_types)?;
+        expr = cast_expr;
+        desc = cast_desc;
+    }
+    let relation_expr = expr.optimize_and_lower(&scx.into());
+
     let names: Vec<ColumnName> = desc.iter_names().cloned().collect();
 
     if let Some(dup) = names.iter().duplicates().next() {
@@ -1427,6 +2144,28 @@ fn kafka_sink_builder(
         None => false,
         Some(_) => bail!("reuse_topic must be a boolean"),
     };
+    let transactional_id_prefix = match with_options.remove("transactional_id_prefix") {
+        None => None,
+        Some(Value::String(prefix)) => {
+            if prefix.is_empty()
+                || !prefix
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+            {
+                bail!(
+                    "transactional_id_prefix must be a non-empty string of ASCII letters, \
+                     digits, '.', '_' and '-', but got '{}'",
+                    prefix
+                );
+            }
+            Some(prefix)
+        }
+        Some(_) => bail!("transactional_id_prefix must be a string"),
+    };
+    if transactional_id_prefix.is_some() && !reuse_topic {
+        // Only exactly-once sinks use a transactional producer.
+        bail!("transactional_id_prefix requires reuse_topic = true");
+    }
     let config_options = kafka_util::extract_config(with_options)?;
 
     let avro_key_fullname = match with_options.remove("avro_key_fullname") {
@@ -1456,6 +2195,7 @@ fn kafka_sink_builder(
             csr_connector:
                 CsrConnectorAvro {
                     url,
+                    reader_schema,
                     seed,
                     with_options,
                 },
@@ -1463,6 +2203,9 @@ fn kafka_sink_builder(
             if seed.is_some() {
                 bail!("SEED option does not make sense with sinks");
             }
+            if reader_schema.is_some() {
+                bail!("READER SCHEMA option does not make sense with sinks");
+            }
             let mut ccsr_with_options = normalize::options(&with_options);
 
             let schema_registry_url = url.parse::<Url>()?;
@@ -1474,19 +2217,13 @@ fn kafka_sink_builder(
 
             let include_transaction =
                 reuse_topic || consistency_topic.is_some() || consistency.is_some();
-            let schema_generator = AvroSchemaGenerator::new(
+            let (key_schema, value_schema) = kafka_sink_avro_schemas(
                 avro_key_fullname.as_deref(),
                 avro_value_fullname.as_deref(),
-                key_desc_and_indices
-                    .as_ref()
-                    .map(|(desc, _indices)| desc.clone()),
-                value_desc.clone(),
+                key_desc_and_indices.as_ref().map(|(desc, _indices)| desc),
+                &value_desc,
                 include_transaction,
             );
-            let value_schema = schema_generator.value_writer_schema().to_string();
-            let key_schema = schema_generator
-                .key_writer_schema()
-                .map(|key_schema| key_schema.to_string());
 
             normalize::ensure_empty_options(&ccsr_with_options, "CONFLUENT SCHEMA REGISTRY")?;
 
@@ -1603,11 +2340,35 @@ fn kafka_sink_builder(
         key_desc_and_indices,
         value_desc,
         reuse_topic,
+        transactional_id_prefix,
         transitive_source_dependencies,
         retention,
     }))
 }
 
+/// Generates the Avro writer schemas for the key (if any) and value of a Kafka
+/// sink, serialized as JSON strings.
+fn kafka_sink_avro_schemas(
+    avro_key_fullname: Option<&str>,
+    avro_value_fullname: Option<&str>,
+    key_desc: Option<&RelationDesc>,
+    value_desc: &RelationDesc,
+    include_transaction: bool,
+) -> (Option<String>, String) {
+    let schema_generator = AvroSchemaGenerator::new(
+        avro_key_fullname,
+        avro_value_fullname,
+        key_desc.cloned(),
+        value_desc.clone(),
+        include_transaction,
+    );
+    let value_schema = schema_generator.value_writer_schema().to_string();
+    let key_schema = schema_generator
+        .key_writer_schema()
+        .map(|key_schema| key_schema.to_string());
+    (key_schema, value_schema)
+}
+
 /// Determines the consistency configuration (topic and format) that should be used for a Kafka
 /// sink based on the given configuration items.
 ///
@@ -1632,6 +2393,7 @@ fn get_kafka_sink_consistency_config(
                 csr_connector:
                     CsrConnectorAvro {
                         url,
+                        reader_schema,
                         seed,
                         with_options,
                     },
@@ -1639,6 +2401,9 @@ fn get_kafka_sink_consistency_config(
                 if seed.is_some() {
                     bail!("SEED option does not make sense with sinks");
                 }
+                if reader_schema.is_some() {
+                    bail!("READER SCHEMA option does not make sense with sinks");
+                }
                 let schema_registry_url = url.parse::<Url>()?;
                 let mut ccsr_with_options = normalize::options(&with_options);
                 let ccsr_config = kafka_util::generate_ccsr_client_config(
@@ -1761,7 +2526,10 @@ pub fn plan_create_sink(
         None | Some(Envelope::Debezium(mz_sql_parser::ast::DbzMode::Plain)) => {
             SinkEnvelope::Debezium
         }
-        Some(Envelope::Upsert) => SinkEnvelope::Upsert,
+        Some(Envelope::Upsert(UpsertValueDecodingErrors::Fail)) => SinkEnvelope::Upsert,
+        Some(Envelope::Upsert(UpsertValueDecodingErrors::Inline)) => {
+            bail!("VALUE DECODING ERRORS = INLINE is not supported for sinks")
+        }
         Some(Envelope::CdcV2) => bail_unsupported!("CDCv2 sinks"),
         Some(Envelope::Debezium(mz_sql_parser::ast::DbzMode::Upsert)) => {
             bail_unsupported!("UPSERT doesn't make sense for sinks")
@@ -1779,6 +2547,7 @@ pub fn plan_create_sink(
     let mut with_options = normalize::options(&with_options);
 
     let desc = from.desc()?;
+    let mut notices = vec![];
     let key_indices = match &connector {
         CreateSinkConnector::Kafka { key, .. } => {
             if let Some(key) = key.clone() {
@@ -1811,12 +2580,10 @@ pub fn plan_create_sink(
                         key_columns.iter().all(|column| indices.contains(column))
                     });
                 if key.not_enforced && envelope == SinkEnvelope::Upsert {
-                    // TODO: We should report a warning notice back to the user via the pgwire
-                    // protocol. See https://github.com/MaterializeInc/materialize/issues/9333.
-                    warn!(
-                        "Verification of upsert key disabled for sink '{}' via 'NOT ENFORCED'. This is potentially dangerous and can lead to crashing materialize when the specified key is not in fact a unique key of the sinked view.",
-                        name
-                    );
+                    notices.push(PlanNotice::UpsertKeyNotEnforced {
+                        sink_name: name.clone(),
+                        key: key_columns.clone(),
+                    });
                 } else if !is_valid_key && envelope == SinkEnvelope::Upsert {
                     return Err(invalid_upsert_key_err(&desc, &key_columns));
                 }
@@ -1880,6 +2647,10 @@ pub fn plan_create_sink(
         }
     };
 
+    if let SinkConnectorBuilder::Kafka(_) = connector_builder {
+        notices.extend(kafka_util::take_misspelled_options(&mut with_options)?);
+    }
+
     normalize::ensure_empty_options(&with_options, "CREATE SINK")?;
 
     Ok(Plan::CreateSink(CreateSinkPlan {
@@ -1893,9 +2664,53 @@ pub fn plan_create_sink(
         },
         with_snapshot,
         if_not_exists,
+        notices,
     }))
 }
 
+pub fn describe_describe_create_sink(
+    _: &StatementContext,
+    _: DescribeCreateSinkStatement<Raw>,
+) -> Result<StatementDesc, anyhow::Error> {
+    Ok(StatementDesc::new(Some(
+        RelationDesc::empty()
+            .with_column("Key Schema", ScalarType::String.nullable(true))
+            .with_column("Value Schema", ScalarType::String.nullable(false)),
+    )))
+}
+
+/// Plans the wrapped `CREATE SINK` statement without creating the sink, and
+/// reports the Avro key and value schemas that the sink would publish.
+pub fn plan_describe_create_sink(
+    scx: &StatementContext,
+    DescribeCreateSinkStatement { stmt }: DescribeCreateSinkStatement<Raw>,
+) -> Result<Plan, anyhow::Error> {
+    let sink = match plan_create_sink(scx, stmt)? {
+        Plan::CreateSink(CreateSinkPlan { sink, .. }) => sink,
+        _ => unreachable!("plan_create_sink always returns a CreateSink plan"),
+    };
+    match sink.connector_builder {
+        SinkConnectorBuilder::Kafka(KafkaSinkConnectorBuilder {
+            format:
+                KafkaSinkFormat::Avro {
+                    key_schema,
+                    value_schema,
+                    ..
+                },
+            ..
+        }) => Ok(Plan::SendRows(SendRowsPlan {
+            rows: vec![Row::pack_slice(&[
+                match &key_schema {
+                    Some(key_schema) => Datum::String(key_schema),
+                    None => Datum::Null,
+                },
+                Datum::String(&value_schema),
+            ])],
+        })),
+        _ => bail!("DESCRIBE CREATE SINK requires an Avro-formatted Kafka sink"),
+    }
+}
+
 fn invalid_upsert_key_err(desc: &RelationDesc, requested_user_key: &[ColumnName]) -> anyhow::Error {
     let requested_user_key = requested_user_key
         .iter()
@@ -2105,6 +2920,7 @@ pub fn plan_create_type(
     let option_keys = match as_type {
         CreateTypeAs::List => vec!["element_type"],
         CreateTypeAs::Map => vec!["key_type", "value_type"],
+        CreateTypeAs::Range => vec!["subtype"],
     };
 
     let mut ids = vec![];
@@ -2188,6 +3004,29 @@ pub fn plan_create_type(
                 value_id: *ids.get(1).expect("value"),
             }
         }
+        CreateTypeAs::Range => {
+            let element_id = *ids.get(0).expect("subtype");
+            let entry = scx.catalog.get_item_by_id(&element_id);
+            match entry.type_details() {
+                Some(CatalogTypeDetails {
+                    typ:
+                        CatalogType::Array { .. }
+                        | CatalogType::List { .. }
+                        | CatalogType::Map { .. }
+                        | CatalogType::Range { .. }
+                        | CatalogType::Pseudo
+                        | CatalogType::Int2Vector,
+                    ..
+                }) => bail!(
+                    "subtype must be an orderable scalar type, got {}",
+                    entry.name()
+                ),
+                Some(_) => {}
+                None => unreachable!("already guaranteed id correlates to a type"),
+            }
+
+            CatalogType::Range { element_id }
+        }
     };
 
     Ok(Plan::CreateType(CreateTypePlan {
@@ -2200,6 +3039,45 @@ pub fn plan_create_type(
     }))
 }
 
+pub fn describe_create_connection(
+    _: &StatementContext,
+    _: CreateConnectionStatement<Raw>,
+) -> Result<StatementDesc, anyhow::Error> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_connection(
+    scx: &StatementContext,
+    stmt: CreateConnectionStatement<Raw>,
+) -> Result<Plan, anyhow::Error> {
+    let create_sql = normalize::create_statement(scx, Statement::CreateConnection(stmt.clone()))?;
+    let CreateConnectionStatement {
+        name,
+        connector,
+        with_options,
+        if_not_exists,
+    } = stmt;
+
+    let mut with_options = normalize::options(&with_options);
+    let connector = match connector {
+        CreateConnectionConnector::Kafka { broker } => Connector::Kafka(KafkaConnector {
+            addrs: broker.parse()?,
+            config_options: kafka_util::extract_config(&mut with_options)?,
+        }),
+    };
+    normalize::ensure_empty_options(&with_options, "CREATE CONNECTION")?;
+
+    let name = scx.allocate_name(normalize::unresolved_object_name(name)?);
+    Ok(Plan::CreateConnection(CreateConnectionPlan {
+        name,
+        if_not_exists,
+        connection: Connection {
+            create_sql,
+            connector,
+        },
+    }))
+}
+
 pub fn describe_create_role(
     _: &StatementContext,
     _: CreateRoleStatement,
@@ -2299,8 +3177,12 @@ pub fn plan_drop_objects(
         if_exists,
         names,
         cascade,
+        with_options,
     }: DropObjectsStatement,
 ) -> Result<Plan, anyhow::Error> {
+    if !with_options.is_empty() && object_type != ObjectType::Source {
+        bail!("DROP {} does not support WITH options", object_type);
+    }
     if materialized {
         bail!(
             "DROP MATERIALIZED {0} is not allowed, use DROP {0}",
@@ -2314,7 +3196,10 @@ pub fn plan_drop_objects(
         | ObjectType::View
         | ObjectType::Index
         | ObjectType::Sink
-        | ObjectType::Type => plan_drop_items(scx, object_type, if_exists, names, cascade),
+        | ObjectType::Type
+        | ObjectType::Connection => {
+            plan_drop_items(scx, object_type, if_exists, names, cascade, with_options)
+        }
         ObjectType::Role => plan_drop_role(scx, if_exists, names),
         ObjectType::Object => unreachable!("cannot drop generic OBJECT, must provide object type"),
     }
@@ -2396,7 +3281,9 @@ pub fn plan_drop_items(
     if_exists: bool,
     names: Vec<UnresolvedObjectName>,
     cascade: bool,
+    with_options: Vec<WithOption>,
 ) -> Result<Plan, anyhow::Error> {
+    let with_options = DropSourceWithOptions::try_from(with_options)?;
     let items = names
         .into_iter()
         .map(|n| scx.resolve_item(n))
@@ -2404,7 +3291,23 @@ pub fn plan_drop_items(
     let mut ids = vec![];
     for item in items {
         match item {
-            Ok(item) => ids.extend(plan_drop_item(scx, object_type, item, cascade)?),
+            Ok(item) => {
+                ids.extend(plan_drop_item(scx, object_type, item, cascade)?);
+                if with_options.drop_slot.is_some()
+                    && !matches!(
+                        item.source_connector(),
+                        Ok(SourceConnector::External {
+                            connector: ExternalSourceConnector::Postgres(_),
+                            ..
+                        })
+                    )
+                {
+                    bail!(
+                        "drop_slot is only valid for Postgres sources, but {} is not a Postgres source",
+                        item.name()
+                    );
+                }
+            }
             Err(_) if if_exists => {
                 // TODO(benesch): generate a notice indicating this
                 // item does not exist.
@@ -2415,9 +3318,16 @@ pub fn plan_drop_items(
     Ok(Plan::DropItems(DropItemsPlan {
         items: ids,
         ty: object_type,
+        drop_slot: with_options.drop_slot.unwrap_or(true),
     }))
 }
 
+with_options! {
+    struct DropSourceWithOptions {
+        drop_slot: bool,
+    }
+}
+
 pub fn plan_drop_item(
     scx: &StatementContext,
     object_type: ObjectType,
@@ -2448,7 +3358,8 @@ pub fn plan_drop_item(
                     | CatalogItemType::Source
                     | CatalogItemType::View
                     | CatalogItemType::Sink
-                    | CatalogItemType::Type => {
+                    | CatalogItemType::Type
+                    | CatalogItemType::Connection => {
                         bail!(
                             "cannot drop {}: still depended upon by catalog item '{}'",
                             catalog_entry.name(),
@@ -2543,6 +3454,215 @@ pub fn plan_alter_index_options(
     }
 }
 
+pub fn describe_alter_view_options(
+    _: &StatementContext,
+    _: AlterViewStatement,
+) -> Result<StatementDesc, anyhow::Error> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_view_options(
+    scx: &StatementContext,
+    AlterViewStatement {
+        view_name,
+        if_exists,
+        action,
+    }: AlterViewStatement,
+) -> Result<Plan, anyhow::Error> {
+    let entry = match scx.resolve_item(view_name) {
+        Ok(view) => view,
+        Err(_) if if_exists => {
+            return Ok(Plan::AlterNoop(AlterNoopPlan {
+                object_type: ObjectType::View,
+            }));
+        }
+        Err(e) => return Err(e.into()),
+    };
+    if entry.item_type() != CatalogItemType::View {
+        bail!("{} is a {} not a view", entry.name(), entry.item_type())
+    }
+    let id = entry.id();
+
+    // A view's options govern the indexes that materialize it, so there is
+    // nothing to alter on a view without any.
+    let materialized = entry.used_by().iter().any(
+        |dep| matches!(scx.catalog.get_item_by_id(dep).index_details(), Some((_, on)) if on == id),
+    );
+    if !materialized {
+        bail!(
+            "cannot alter options of view {}: view is not materialized",
+            entry.name()
+        );
+    }
+
+    match action {
+        AlterViewAction::ResetOptions(options) => {
+            let mut unknown = vec![];
+            let mut names = vec![];
+            for o in options {
+                match normalize::ident(o).as_str() {
+                    "logical_compaction_window" => {
+                        names.push(IndexOptionName::LogicalCompactionWindow)
+                    }
+                    other => unknown.push(other.to_string()),
+                }
+            }
+            if !unknown.is_empty() {
+                bail!(
+                    "unexpected parameters for ALTER VIEW ... RESET: {}",
+                    unknown.join(",")
+                );
+            }
+            Ok(Plan::AlterViewResetOptions(AlterViewResetOptionsPlan {
+                id,
+                options: names,
+            }))
+        }
+        AlterViewAction::SetOptions(options) => {
+            let options = plan_index_options(options)?;
+            Ok(Plan::AlterViewSetOptions(AlterViewSetOptionsPlan {
+                id,
+                options,
+            }))
+        }
+    }
+}
+
+pub fn describe_alter_source(
+    _: &StatementContext,
+    _: AlterSourceStatement,
+) -> Result<StatementDesc, anyhow::Error> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_source(
+    scx: &StatementContext,
+    AlterSourceStatement {
+        source_name,
+        if_exists,
+        action,
+    }: AlterSourceStatement,
+) -> Result<Plan, anyhow::Error> {
+    let entry = match scx.resolve_item(source_name) {
+        Ok(source) => source,
+        Err(_) if if_exists => {
+            return Ok(Plan::AlterNoop(AlterNoopPlan {
+                object_type: ObjectType::Source,
+            }));
+        }
+        Err(e) => return Err(e.into()),
+    };
+    if entry.item_type() != CatalogItemType::Source {
+        bail!("{} is a {} not a source", entry.name(), entry.item_type())
+    }
+
+    match action {
+        AlterSourceAction::RenameColumn {
+            column,
+            to_column_name,
+        } => plan_alter_source_rename_column(scx, entry, column, to_column_name),
+    }
+}
+
+fn plan_alter_source_rename_column(
+    scx: &StatementContext,
+    entry: &dyn CatalogItem,
+    column: Ident,
+    to_column_name: Ident,
+) -> Result<Plan, anyhow::Error> {
+    let column = normalize::column_name(column);
+    let to_column_name = normalize::column_name(to_column_name);
+    let desc = entry.desc()?;
+
+    let idx = match desc.get_by_name(&column) {
+        Some((idx, _)) => idx,
+        None => bail!(
+            "column {} of source {} does not exist",
+            column.as_str().quoted(),
+            entry.name()
+        ),
+    };
+    if desc.get_unambiguous_name(idx).is_none() {
+        bail!(
+            "column reference {} of source {} is ambiguous",
+            column.as_str().quoted(),
+            entry.name()
+        );
+    }
+    if desc.get_by_name(&to_column_name).is_some() {
+        bail!(
+            "column {} of source {} already exists",
+            to_column_name.as_str().quoted(),
+            entry.name()
+        );
+    }
+    if let SourceConnector::External { connector, .. } = entry.source_connector()? {
+        if connector.default_metadata_column_name() == Some(to_column_name.as_str()) {
+            bail!(
+                "column name {} is reserved for source metadata",
+                to_column_name.as_str().quoted()
+            );
+        }
+    }
+
+    // Indexes are rewritten to use the new name. Other dependents refer to
+    // the column by name in ways we cannot safely update.
+    let mut indexes = vec![];
+    for id in entry.used_by() {
+        let dep = scx.catalog.get_item_by_id(id);
+        if dep.item_type() != CatalogItemType::Index {
+            bail!(
+                "cannot rename column {} of source {}: still depended upon by catalog item '{}'",
+                column.as_str().quoted(),
+                entry.name(),
+                dep.name()
+            );
+        }
+        let mut stmt = crate::parse::parse(dep.create_sql())?.into_element();
+        crate::ast::transform::create_index_stmt_rename_column(
+            &mut stmt,
+            &Ident::new(column.as_str()),
+            &Ident::new(to_column_name.as_str()),
+        );
+        indexes.push((*id, stmt.to_ast_string_stable()));
+    }
+
+    let names = desc
+        .iter_names()
+        .enumerate()
+        .map(|(i, name)| {
+            if i == idx {
+                to_column_name.clone()
+            } else {
+                name.clone()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Record every column name in the source's definition, and update the
+    // clauses that refer to the column by name, so that replanning it produces
+    // the renamed description.
+    let create_sql = match crate::parse::parse(entry.create_sql())?.into_element() {
+        Statement::CreateSource(mut stmt) => {
+            stmt.col_names = names.iter().map(|n| Ident::new(n.as_str())).collect();
+            crate::ast::transform::create_source_stmt_rename_column(
+                &mut stmt,
+                &Ident::new(column.as_str()),
+                &Ident::new(to_column_name.as_str()),
+            );
+            stmt.to_ast_string_stable()
+        }
+        _ => unreachable!("source is defined by CREATE SOURCE"),
+    };
+
+    Ok(Plan::AlterSourceRenameColumn(AlterSourceRenameColumnPlan {
+        id: entry.id(),
+        create_sql,
+        desc: desc.clone().with_names(names),
+        indexes,
+    }))
+}
+
 pub fn describe_alter_object_rename(
     _: &StatementContext,
     _: AlterObjectRenameStatement,
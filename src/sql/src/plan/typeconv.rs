@@ -399,6 +399,16 @@ lazy_static! {
                 Some(|e: HirScalarExpr| e.call_unary(CastArrayToString { ty }))
             }),
             (Array, List) => Explicit: CastArrayToListOneDim(func::CastArrayToListOneDim),
+            (Array, Array) => Assignment: CastTemplate::new(|ecx, ccx, from_type, to_type| {
+                let return_ty = to_type.clone();
+                let from_el_type = from_type.unwrap_array_element_type();
+                let to_el_type = to_type.unwrap_array_element_type();
+                let cast_expr = plan_hypothetical_cast(ecx, ccx, from_el_type, to_el_type)?;
+                Some(|e: HirScalarExpr| e.call_unary(UnaryFunc::CastArrayToArray(func::CastArrayToArray {
+                    return_ty,
+                    cast_expr: Box::new(cast_expr),
+                })))
+            }),
 
             // INT2VECTOR
             (Int2Vector, Array) => Implicit: CastTemplate::new(|_ecx, _ccx, _from_type, _to_type| {
@@ -34,16 +34,20 @@ use mz_dataflow_types::sources::{AwsConfig, AwsExternalId};
 use mz_dataflow_types::sources::{
     ExternalSourceConnector, PostgresSourceConnector, SourceConnector,
 };
+use mz_interchange::avro::ConfluentWireFormat;
+use mz_ore::str::StrExt;
 use mz_repr::strconv;
 use mz_sql_parser::parser::parse_data_type;
 
 use crate::ast::{
-    AvroSchema, CreateSourceConnector, CreateSourceFormat, CreateSourceStatement,
-    CreateViewsDefinitions, CreateViewsSourceTarget, CreateViewsStatement, CsrConnectorAvro,
-    CsrConnectorProto, CsrSeed, CsrSeedCompiled, CsrSeedCompiledEncoding, CsrSeedCompiledOrLegacy,
-    CsvColumns, DbzMode, Envelope, Expr, Format, Ident, Op, ProtobufSchema, Query, Raw, RawName,
-    Select, SelectItem, SetExpr, SqlOption, Statement, SubscriptPosition, TableFactor,
-    TableWithJoins, UnresolvedObjectName, Value, ViewDefinition, WithOption, WithOptionValue,
+    AvroSchema, CreateSinkConnector, CreateSinkStatement, CreateSourceConnector,
+    CreateSourceFormat, CreateSourceStatement, CreateViewsDefinitions, CreateViewsSourceTarget,
+    CreateViewsStatement, CsrConnectorAvro, CsrConnectorProto, CsrSeed, CsrSeedCompiled,
+    CsrSeedCompiledEncoding, CsrSeedCompiledOrLegacy, CsvColumns, DbzMode,
+    DescribeCreateSinkStatement, Envelope, ExplainEncodingStatement, Expr, Format, Ident,
+    KafkaConnection, Op, ProtobufSchema, Query, Raw, RawName, Select, SelectItem, SetExpr,
+    SqlOption, Statement, SubscriptPosition, TableFactor, TableWithJoins, UnresolvedObjectName,
+    Value, ViewDefinition, WithOption, WithOptionValue,
 };
 use crate::catalog::SessionCatalog;
 use crate::kafka_util;
@@ -60,8 +64,20 @@ use crate::normalize;
 /// locking access to the catalog for an unbounded amount of time.
 pub fn purify(
     catalog: &dyn SessionCatalog,
-    mut stmt: Statement<Raw>,
+    stmt: Statement<Raw>,
 ) -> impl Future<Output = Result<Statement<Raw>, anyhow::Error>> {
+    // EXPLAIN ENCODING and DESCRIBE CREATE SINK plan the source or sink they
+    // wrap, so it needs the same purification as if it were being created.
+    let (explain_encoding, describe_create_sink, mut stmt) = match stmt {
+        Statement::ExplainEncoding(ExplainEncodingStatement { stmt }) => {
+            (true, false, Statement::CreateSource(stmt))
+        }
+        Statement::DescribeCreateSink(DescribeCreateSinkStatement { stmt }) => {
+            (false, true, Statement::CreateSink(stmt))
+        }
+        stmt => (false, false, stmt),
+    };
+
     // If we're dealing with a CREATE VIEWS statement we need to query the catalog for the
     // corresponding source connector and store it before we enter the async section.
     let source_connector = if let Statement::CreateViews(CreateViewsStatement {
@@ -82,6 +98,20 @@ pub fn purify(
         Err(anyhow!("SQL statement does not refer to a source"))
     };
 
+    // Likewise, a Kafka source that uses a named connection needs the
+    // connection's details resolved before we enter the async section.
+    let kafka_connection = match &stmt {
+        Statement::CreateSource(CreateSourceStatement {
+            connector:
+                CreateSourceConnector::Kafka {
+                    connection: KafkaConnection::Reference { connection },
+                    ..
+                },
+            ..
+        }) => Some(kafka_util::resolve_connection(catalog, connection)),
+        _ => None,
+    };
+
     let now = catalog.now();
     let aws_external_id = catalog.config().aws_external_id.clone();
 
@@ -100,19 +130,41 @@ pub fn purify(
 
             let mut file = None;
             match connector {
-                CreateSourceConnector::Kafka { broker, topic, .. } => {
-                    if !broker.contains(':') {
-                        *broker += ":9092";
-                    }
-
+                CreateSourceConnector::Kafka {
+                    connection, topic, ..
+                } => {
                     // Verify that the provided security options are valid and then test them.
-                    config_options = kafka_util::extract_config(&mut with_options_map)?;
+                    let broker = match connection {
+                        KafkaConnection::Inline { broker } => {
+                            if !broker.contains(':') {
+                                *broker += ":9092";
+                            }
+                            config_options = kafka_util::extract_config(&mut with_options_map)?;
+                            broker.clone()
+                        }
+                        KafkaConnection::Reference { .. } => {
+                            let (_, connector) =
+                                kafka_connection.expect("connection resolved above")?;
+                            config_options = kafka_util::extract_config_with_connection(
+                                &mut with_options_map,
+                                &connector,
+                            )?;
+                            connector.addrs.to_string()
+                        }
+                    };
                     let consumer = kafka_util::create_consumer(&broker, &topic, &config_options)
                         .await
                         .map_err(|e| {
                             anyhow!("Failed to create and connect Kafka consumer: {}", e)
                         })?;
 
+                    kafka_util::validate_partition_count(
+                        Arc::clone(&consumer),
+                        &topic,
+                        &with_options_map,
+                    )
+                    .await?;
+
                     // Translate `kafka_time_offset` to `start_offset`.
                     match kafka_util::lookup_start_offsets(
                         Arc::clone(&consumer),
@@ -216,6 +268,21 @@ pub fn purify(
             .await?;
         }
 
+        if let Statement::CreateSink(CreateSinkStatement {
+            name,
+            from,
+            connector: CreateSinkConnector::Kafka { topic, .. },
+            ..
+        }) = &mut stmt
+        {
+            *topic = purify_sink_topic_template(
+                topic,
+                &normalize::unresolved_object_name(name.clone())?.item,
+                &normalize::unresolved_object_name(from.clone())?.item,
+                now,
+            )?;
+        }
+
         if let Statement::CreateViews(CreateViewsStatement { definitions, .. }) = &mut stmt {
             if let CreateViewsDefinitions::Source {
                 name: source_name,
@@ -360,6 +427,7 @@ pub fn purify(
                                     .iter()
                                     .map(|c| Ident::new(c.name.clone()))
                                     .collect(),
+                                column_types: vec![],
                                 with_options: vec![],
                                 query,
                             });
@@ -375,8 +443,67 @@ pub fn purify(
                 }
             }
         }
-        Ok(stmt)
+        match stmt {
+            Statement::CreateSource(stmt) if explain_encoding => {
+                Ok(Statement::ExplainEncoding(ExplainEncodingStatement {
+                    stmt,
+                }))
+            }
+            Statement::CreateSink(stmt) if describe_create_sink => {
+                Ok(Statement::DescribeCreateSink(DescribeCreateSinkStatement {
+                    stmt,
+                }))
+            }
+            stmt => Ok(stmt),
+        }
+    }
+}
+
+/// Resolves the variables in the topic of a Kafka sink.
+///
+/// The topic may refer to `{sink_name}` and `{source_name}`, the unqualified
+/// names of the sink and of the relation it exports, and to `{timestamp}`, the
+/// time at which the sink was purified in milliseconds since the Unix epoch.
+/// The resolved topic must be a legal Kafka topic name.
+///
+/// Resolving the template during purification records the concrete topic in
+/// the sink's definition, so that the sink keeps writing to the same topic
+/// when its definition is replanned.
+fn purify_sink_topic_template(
+    template: &str,
+    sink_name: &str,
+    source_name: &str,
+    timestamp: u64,
+) -> Result<String, anyhow::Error> {
+    let mut topic = String::new();
+    let mut chars = template.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => {
+                let rest = chars.as_str();
+                let end = match rest.find('}') {
+                    Some(end) => end,
+                    None => bail!("unterminated variable in sink topic {}", template.quoted()),
+                };
+                match &rest[..end] {
+                    "sink_name" => topic.push_str(sink_name),
+                    "source_name" => topic.push_str(source_name),
+                    "timestamp" => topic.push_str(&timestamp.to_string()),
+                    var => bail!(
+                        "unknown variable \"{{{}}}\" in sink topic {}; \
+                         expected one of {{sink_name}}, {{source_name}}, or {{timestamp}}",
+                        var.escape_default(),
+                        template.quoted()
+                    ),
+                }
+                chars = rest[end + 1..].chars();
+            }
+            '}' => bail!("unmatched }} in sink topic {}", template.quoted()),
+            c => topic.push(c),
+        }
     }
+    kafka_util::validate_topic_name(&topic)?;
+    Ok(topic)
 }
 
 async fn purify_source_format(
@@ -402,7 +529,7 @@ async fn purify_source_format(
     match (&connector, &envelope, &*format) {
         (
             CreateSourceConnector::Kafka { .. },
-            Envelope::Upsert,
+            Envelope::Upsert(_),
             CreateSourceFormat::Bare(f @ Format::Bytes | f @ Format::Text),
         ) => {
             *format = CreateSourceFormat::KeyValue {
@@ -423,6 +550,7 @@ async fn purify_source_format(
                 file,
                 connector_options,
                 with_options,
+                true,
             )
             .await?;
         }
@@ -440,8 +568,11 @@ async fn purify_source_format(
                 None,
                 connector_options,
                 with_options,
+                true,
             )
             .await?;
+            // The key is decoded with its own format, so the value's schema
+            // registry must not be asked for a key schema.
             purify_source_format_single(
                 val,
                 connector,
@@ -449,6 +580,7 @@ async fn purify_source_format(
                 None,
                 connector_options,
                 with_options,
+                false,
             )
             .await?;
         }
@@ -456,6 +588,10 @@ async fn purify_source_format(
     Ok(())
 }
 
+/// Purifies a single format.
+///
+/// If `fetch_key_schema` is set, a format backed by a schema registry also
+/// looks up the key schema of the topic.
 async fn purify_source_format_single(
     format: &mut Format<Raw>,
     connector: &mut CreateSourceConnector,
@@ -463,12 +599,19 @@ async fn purify_source_format_single(
     file: Option<File>,
     connector_options: &BTreeMap<String, String>,
     with_options: &Vec<SqlOption<Raw>>,
+    fetch_key_schema: bool,
 ) -> Result<(), anyhow::Error> {
     match format {
         Format::Avro(schema) => match schema {
             AvroSchema::Csr { csr_connector } => {
-                purify_csr_connector_avro(connector, csr_connector, envelope, connector_options)
-                    .await?
+                purify_csr_connector_avro(
+                    connector,
+                    csr_connector,
+                    envelope,
+                    connector_options,
+                    fetch_key_schema,
+                )
+                .await?
             }
             AvroSchema::InlineSchema {
                 schema: mz_sql_parser::ast::Schema::File(path),
@@ -498,8 +641,14 @@ async fn purify_source_format_single(
         },
         Format::Protobuf(schema) => match schema {
             ProtobufSchema::Csr { csr_connector } => {
-                purify_csr_connector_proto(connector, csr_connector, envelope, with_options)
-                    .await?;
+                purify_csr_connector_proto(
+                    connector,
+                    csr_connector,
+                    envelope,
+                    with_options,
+                    fetch_key_schema,
+                )
+                .await?;
             }
             ProtobufSchema::InlineSchema {
                 message_name: _,
@@ -516,8 +665,9 @@ async fn purify_source_format_single(
         Format::Csv {
             delimiter,
             ref mut columns,
+            ref mut with_options,
         } => {
-            purify_csv(file, connector, *delimiter, columns).await?;
+            purify_csv(file, connector, *delimiter, columns, with_options).await?;
         }
         Format::Bytes | Format::Regex(_) | Format::Json | Format::Text => (),
     }
@@ -529,6 +679,7 @@ async fn purify_csr_connector_proto(
     csr_connector: &mut CsrConnectorProto<Raw>,
     envelope: &Envelope,
     with_options: &Vec<SqlOption<Raw>>,
+    fetch_key_schema: bool,
 ) -> Result<(), anyhow::Error> {
     let topic = if let CreateSourceConnector::Kafka { topic, .. } = connector {
         topic
@@ -553,11 +704,18 @@ async fn purify_csr_connector_proto(
 
             let value =
                 compile_proto(&format!("{}-value", topic), ccsr_config.clone().build()?).await?;
-            let key = compile_proto(&format!("{}-key", topic), ccsr_config.build()?)
-                .await
-                .ok();
+            let key = if fetch_key_schema {
+                compile_proto(&format!("{}-key", topic), ccsr_config.build()?)
+                    .await
+                    .ok()
+            } else {
+                None
+            };
 
-            if matches!(envelope, Envelope::Debezium(DbzMode::Upsert)) && key.is_none() {
+            if fetch_key_schema
+                && matches!(envelope, Envelope::Debezium(DbzMode::Upsert))
+                && key.is_none()
+            {
                 bail!("Key schema is required for ENVELOPE DEBEZIUM UPSERT");
             }
 
@@ -580,6 +738,7 @@ async fn purify_csr_connector_avro(
     csr_connector: &mut CsrConnectorAvro<Raw>,
     envelope: &Envelope,
     connector_options: &BTreeMap<String, String>,
+    fetch_key_schema: bool,
 ) -> Result<(), anyhow::Error> {
     let topic = if let CreateSourceConnector::Kafka { topic, .. } = connector {
         topic
@@ -591,24 +750,32 @@ async fn purify_csr_connector_avro(
         url,
         seed,
         with_options: ccsr_options,
+        ..
     } = csr_connector;
     if seed.is_none() {
         let url = url.parse()?;
 
+        let mut ccsr_options = normalize::options(ccsr_options);
+        let key_strategy = kafka_util::extract_subject_name_strategy(&mut ccsr_options, "key")?;
+        let value_strategy = kafka_util::extract_subject_name_strategy(&mut ccsr_options, "value")?;
         let ccsr_config = task::block_in_place(|| {
-            kafka_util::generate_ccsr_client_config(
-                url,
-                &connector_options,
-                &mut normalize::options(ccsr_options),
-            )
+            kafka_util::generate_ccsr_client_config(url, &connector_options, &mut ccsr_options)
         })?;
 
         let Schema {
             key_schema,
             value_schema,
             ..
-        } = get_remote_csr_schema(ccsr_config, topic.clone()).await?;
-        if matches!(envelope, Envelope::Debezium(DbzMode::Upsert)) && key_schema.is_none() {
+        } = get_remote_csr_schema(
+            ccsr_config,
+            fetch_key_schema.then(|| key_strategy.subject(topic, "key")),
+            value_strategy.subject(topic, "value"),
+        )
+        .await?;
+        if fetch_key_schema
+            && matches!(envelope, Envelope::Debezium(DbzMode::Upsert))
+            && key_schema.is_none()
+        {
             bail!("Key schema is required for ENVELOPE DEBEZIUM UPSERT");
         }
 
@@ -626,6 +793,7 @@ pub async fn purify_csv(
     connector: &CreateSourceConnector,
     delimiter: char,
     columns: &mut CsvColumns,
+    with_options: &mut Vec<WithOption>,
 ) -> anyhow::Result<()> {
     if matches!(columns, CsvColumns::Header { .. })
         && !matches!(
@@ -636,6 +804,20 @@ pub async fn purify_csv(
         bail_unsupported!("CSV WITH HEADER with non-file or S3 sources");
     }
 
+    // Whether the column count is to be inferred from the first row. Invalid
+    // combinations of options are reported during planning.
+    let infer_columns = matches!(columns, CsvColumns::Infer)
+        && with_options.iter().any(|option| {
+            option.key.as_str() == "infer_columns"
+                && matches!(
+                    option.value,
+                    None | Some(WithOptionValue::Value(Value::Boolean(true)))
+                )
+        });
+    if infer_columns && !matches!(connector, CreateSourceConnector::File { .. }) {
+        bail_unsupported!("CSV infer_columns with non-file sources");
+    }
+
     let first_row = if let Some(file) = file {
         let file = tokio::io::BufReader::new(file);
         let csv_header = file.lines().next_line().await;
@@ -658,6 +840,11 @@ pub async fn purify_csv(
                     None
                 }
             }
+            Ok(None) if infer_columns => bail!(
+                "CSV file expected to have at least one line \
+                 to infer the column count, but is empty"
+            ),
+            Err(e) if infer_columns => bail!("Cannot infer column count from CSV file: {}", e),
             Ok(None) => {
                 if let CsvColumns::Header { names } = columns {
                     if names.is_empty() {
@@ -733,6 +920,14 @@ pub async fn purify_csv(
             );
         }
 
+        (CsvColumns::Infer, Some(columns_in_first_row)) if infer_columns => {
+            // The column count is fixed by the first row. Later rows with a
+            // different number of columns fail to decode.
+            *columns = CsvColumns::Count(columns_in_first_row.len());
+            with_options.retain(|option| option.key.as_str() != "infer_columns");
+        }
+        (CsvColumns::Infer, _) => (),
+
         (CsvColumns::Count(n), first_line) => {
             if let Some(columns) = first_line {
                 if *n != columns.len() {
@@ -754,36 +949,38 @@ pub struct Schema {
     pub key_schema: Option<String>,
     pub value_schema: String,
     pub schema_registry_config: Option<mz_ccsr::ClientConfig>,
-    pub confluent_wire_format: bool,
+    pub confluent_wire_format: ConfluentWireFormat,
 }
 
 async fn get_remote_csr_schema(
     schema_registry_config: mz_ccsr::ClientConfig,
-    topic: String,
+    key_subject: Option<String>,
+    value_subject: String,
 ) -> Result<Schema, anyhow::Error> {
     let ccsr_client = schema_registry_config.clone().build()?;
 
-    let value_schema_name = format!("{}-value", topic);
     let value_schema = ccsr_client
-        .get_schema_by_subject(&value_schema_name)
+        .get_schema_by_subject(&value_subject)
         .await
         .with_context(|| {
             format!(
                 "fetching latest schema for subject '{}' from registry",
-                value_schema_name
+                value_subject
             )
         })?;
-    let subject = format!("{}-key", topic);
-    let key_schema = match ccsr_client.get_schema_by_subject(&subject).await {
-        Ok(ks) => Some(ks),
-        Err(GetBySubjectError::SubjectNotFound) => None,
-        Err(e) => bail!(e),
+    let key_schema = match key_subject {
+        Some(key_subject) => match ccsr_client.get_schema_by_subject(&key_subject).await {
+            Ok(ks) => Some(ks),
+            Err(GetBySubjectError::SubjectNotFound) => None,
+            Err(e) => bail!(e),
+        },
+        None => None,
     };
     Ok(Schema {
         key_schema: key_schema.map(|s| s.raw),
         value_schema: value_schema.raw,
         schema_registry_config: Some(schema_registry_config),
-        confluent_wire_format: true,
+        confluent_wire_format: ConfluentWireFormat::Enabled,
     })
 }
 
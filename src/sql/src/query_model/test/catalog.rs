@@ -18,6 +18,7 @@ use crate::plan::StatementDesc;
 use chrono::MIN_DATETIME;
 use lazy_static::lazy_static;
 use mz_build_info::DUMMY_BUILD_INFO;
+use mz_dataflow_types::connections::Connector;
 use mz_dataflow_types::sources::{AwsExternalId, SourceConnector};
 use mz_expr::{DummyHumanizer, ExprHumanizer, GlobalId, MirScalarExpr};
 use mz_lowertest::*;
@@ -108,6 +109,10 @@ impl CatalogItem for TestCatalogItem {
         unimplemented!()
     }
 
+    fn connector(&self) -> Result<&Connector, CatalogError> {
+        unimplemented!()
+    }
+
     fn item_type(&self) -> CatalogItemType {
         match &self {
             TestCatalogItem::BaseTable { .. } => CatalogItemType::View,
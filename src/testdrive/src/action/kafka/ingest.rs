@@ -8,12 +8,13 @@
 // by the Apache License, Version 2.0.
 
 use std::cmp;
-use std::io::{BufRead, Read};
+use std::io::{self, BufRead, Read, Write};
 use std::time::Duration;
 
 use anyhow::{anyhow, bail, Context};
 use async_trait::async_trait;
 use byteorder::{NetworkEndian, WriteBytesExt};
+use flate2::write::GzEncoder;
 use futures::stream::{FuturesUnordered, StreamExt};
 use maplit::hashmap;
 use prost::Message;
@@ -22,6 +23,7 @@ use rdkafka::producer::FutureRecord;
 use serde::de::DeserializeOwned;
 use tokio::fs;
 
+use crate::action::file::{build_compression, Compression};
 use crate::action::{self, Action, ControlFlow, State};
 use crate::format::avro::{self, Schema};
 use crate::format::bytes;
@@ -36,6 +38,7 @@ pub struct IngestAction {
     key_format: Option<Format>,
     timestamp: Option<i64>,
     publish: bool,
+    compression: Compression,
     rows: Vec<String>,
     start_iteration: isize,
     repeat: isize,
@@ -223,6 +226,7 @@ pub fn build_ingest(mut cmd: BuiltinCommand) -> Result<IngestAction, anyhow::Err
         None => None,
     };
     let timestamp = cmd.args.opt_parse("timestamp")?;
+    let compression = build_compression(&mut cmd)?;
     cmd.args.done()?;
 
     if publish
@@ -239,6 +243,7 @@ pub fn build_ingest(mut cmd: BuiltinCommand) -> Result<IngestAction, anyhow::Err
         key_format,
         timestamp,
         publish,
+        compression,
         rows: cmd.input,
         start_iteration,
         repeat,
@@ -367,6 +372,10 @@ impl Action for IngestAction {
                 let value = value_transcoder
                     .transcode(&mut row)
                     .with_context(|| format!("parsing row: {}", String::from_utf8_lossy(row)))?;
+                let value = match (&self.compression, value) {
+                    (Compression::Gzip, Some(value)) => Some(gzip(&value)?),
+                    (_, value) => value,
+                };
                 let producer = &state.kafka_producer;
                 let timeout = cmp::max(state.default_timeout, Duration::from_secs(1));
                 futs.push(async move {
@@ -400,3 +409,9 @@ impl Action for IngestAction {
         Ok(ControlFlow::Continue)
     }
 }
+
+fn gzip(bytes: &[u8]) -> Result<Vec<u8>, io::Error> {
+    let mut encoder = GzEncoder::new(vec![], flate2::Compression::default());
+    encoder.write_all(bytes)?;
+    encoder.finish()
+}
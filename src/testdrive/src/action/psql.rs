@@ -58,7 +58,12 @@ impl Action for ExecuteAction {
                 String::from_utf8_lossy(&output.stderr),
             );
         }
-        let stdout = text::trim_trailing_space(&String::from_utf8_lossy(&output.stdout));
+        // `psql` writes notices to stderr; compare them after the output.
+        let stdout = text::trim_trailing_space(&format!(
+            "{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        ));
         if self.expected_output != stdout {
             text::print_diff(&self.expected_output, &*stdout);
             bail!("psql returned unexpected output (diff above)");
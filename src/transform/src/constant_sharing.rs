@@ -0,0 +1,120 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Shares the values of constant `Map` expressions rather than recomputing them.
+//!
+//! A `Map` scalar is constant when it references no columns and calls no
+//! unmaterializable functions. This transform replaces a constant scalar
+//! that repeats one computed earlier, either by the same `Map` or by a `Map`
+//! beneath a `Project` that discarded it, with a reference to the column
+//! holding the earlier computation.
+//!
+//! Literals are left alone, as they cost nothing to recompute and
+//! [`LiteralLifting`](crate::map_lifting::LiteralLifting) already hoists
+//! them. Running [`FoldConstants`](crate::reduction::FoldConstants) after
+//! this transform folds each shared constant once, rather than once per
+//! copy.
+
+use mz_expr::{MirRelationExpr, MirScalarExpr};
+
+use crate::TransformArgs;
+
+/// Shares the values of constant `Map` expressions rather than recomputing them.
+#[derive(Debug)]
+pub struct ConstantSharing;
+
+impl crate::Transform for ConstantSharing {
+    fn transform(
+        &self,
+        relation: &mut MirRelationExpr,
+        _: TransformArgs,
+    ) -> Result<(), crate::TransformError> {
+        relation.visit_mut_post(&mut |e| self.action(e));
+        Ok(())
+    }
+}
+
+impl ConstantSharing {
+    /// Shares the values of constant `Map` expressions rather than recomputing them.
+    pub fn action(&self, relation: &mut MirRelationExpr) {
+        let mut project = None;
+        if let MirRelationExpr::Map { input, scalars } = relation {
+            let input_arity = input.arity();
+
+            // Reuse constants computed earlier by the same `Map`.
+            for i in 0..scalars.len() {
+                if is_shareable(&scalars[i]) {
+                    if let Some(j) = scalars[..i].iter().position(|s| *s == scalars[i]) {
+                        scalars[i] = MirScalarExpr::Column(input_arity + j);
+                    }
+                }
+            }
+
+            // Reuse constants computed by a `Map` whose result was projected
+            // away, by retaining them in the `Project`.
+            if let MirRelationExpr::Project {
+                input: inner,
+                outputs,
+            } = &mut **input
+            {
+                if let MirRelationExpr::Map {
+                    input: inner_input,
+                    scalars: inner_scalars,
+                } = &**inner
+                {
+                    let inner_input_arity = inner_input.arity();
+                    let shared = scalars
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, scalar)| is_shareable(scalar))
+                        .filter_map(|(i, scalar)| {
+                            let j = inner_scalars.iter().position(|s| s == scalar)?;
+                            Some((i, inner_input_arity + j))
+                        })
+                        .collect::<Vec<_>>();
+
+                    if !shared.is_empty() {
+                        // The retained columns are placed between the input
+                        // columns and those computed by this `Map`.
+                        let num_shared = shared.len();
+                        for scalar in scalars.iter_mut() {
+                            scalar.visit_mut_post(&mut |e| {
+                                if let MirScalarExpr::Column(c) = e {
+                                    if *c >= input_arity {
+                                        *c += num_shared;
+                                    }
+                                }
+                            });
+                        }
+                        for (k, (i, column)) in shared.into_iter().enumerate() {
+                            scalars[i] = MirScalarExpr::Column(input_arity + k);
+                            outputs.push(column);
+                        }
+                        project = Some(
+                            (0..input_arity)
+                                .chain(
+                                    input_arity + num_shared
+                                        ..input_arity + num_shared + scalars.len(),
+                                )
+                                .collect::<Vec<_>>(),
+                        );
+                    }
+                }
+            }
+        }
+        if let Some(outputs) = project {
+            *relation = relation.take_dangerous().project(outputs);
+        }
+    }
+}
+
+/// Reports whether `scalar` is a constant worth computing only once.
+fn is_shareable(scalar: &MirScalarExpr) -> bool {
+    !scalar.is_literal() && !scalar.contains_nullary() && scalar.support().is_empty()
+}
@@ -0,0 +1,167 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Hoists function calls repeated across `Filter` predicates into a `Map`.
+//!
+//! A `Filter` with predicates like `f(#0) > 0` and `f(#0) < 10` evaluates
+//! `f(#0)` once per occurrence for every row. This transform computes each
+//! repeated call once, in a `Map` beneath the `Filter`, rewrites the
+//! predicates to reference the mapped column, and projects the column away
+//! above the `Filter`.
+//!
+//! Only occurrences that are evaluated whenever their predicate is are
+//! considered. Those in the branches of an `If`, in the second operand of an
+//! `AND` or `OR`, or past the first argument of a `COALESCE` may not be
+//! evaluated at all, and computing them for every row could surface errors
+//! the `Filter` would not. The predicates themselves form a conjunction with
+//! no evaluation order: other transforms reorder them, and a
+//! `MapFilterProject` evaluates them by the columns they reference. So a call
+//! that any predicate evaluates unconditionally may be computed for every
+//! row without surfacing new errors.
+//!
+//! Calls without column references are left to
+//! [`FoldConstants`](crate::reduction::FoldConstants), and calls involving
+//! unmaterializable functions are left in place so that temporal filters
+//! are still recognized as such.
+
+use std::collections::HashMap;
+
+use mz_expr::{BinaryFunc, MirRelationExpr, MirScalarExpr, VariadicFunc};
+
+use crate::TransformArgs;
+
+/// Hoists function calls repeated across `Filter` predicates into a `Map`.
+#[derive(Debug)]
+pub struct FilterCSE;
+
+impl crate::Transform for FilterCSE {
+    fn transform(
+        &self,
+        relation: &mut MirRelationExpr,
+        _: TransformArgs,
+    ) -> Result<(), crate::TransformError> {
+        relation.visit_mut_post(&mut |e| self.action(e));
+        Ok(())
+    }
+}
+
+impl FilterCSE {
+    /// Hoists function calls repeated across `Filter` predicates into a `Map`.
+    pub fn action(&self, relation: &mut MirRelationExpr) {
+        if let MirRelationExpr::Filter { input, predicates } = relation {
+            let input_arity = input.arity();
+            // Calls are hoisted innermost first, so that a later call may
+            // reference the columns of those hoisted before it.
+            let mut scalars = Vec::new();
+            while let Some(call) = first_repeated_call(predicates) {
+                let column = input_arity + scalars.len();
+                for predicate in predicates.iter_mut() {
+                    replace_unconditional(predicate, &call, column);
+                }
+                scalars.push(call);
+            }
+            if scalars.is_empty() {
+                return;
+            }
+
+            let predicates = std::mem::take(predicates);
+            let filter = input
+                .take_dangerous()
+                .map(scalars)
+                .filter(predicates)
+                .project((0..input_arity).collect());
+            *relation = filter;
+        }
+    }
+}
+
+/// Returns the first call, in post-order, that `predicates` unconditionally
+/// evaluate more than once.
+///
+/// As the subexpressions of a call precede it in post-order, the returned
+/// call contains no other repeated call.
+fn first_repeated_call(predicates: &[MirScalarExpr]) -> Option<MirScalarExpr> {
+    let mut calls = Vec::new();
+    let mut counts = HashMap::new();
+    for predicate in predicates {
+        visit_unconditional(predicate, &mut |e| {
+            if is_hoistable(e) {
+                let count = counts.entry(e).or_insert(0);
+                if *count == 0 {
+                    calls.push(e);
+                }
+                *count += 1;
+            }
+        });
+    }
+    calls.into_iter().find(|call| counts[call] > 1).cloned()
+}
+
+/// Reports whether `expr` is a call worth computing only once.
+fn is_hoistable(expr: &MirScalarExpr) -> bool {
+    matches!(
+        expr,
+        MirScalarExpr::CallUnary { .. }
+            | MirScalarExpr::CallBinary { .. }
+            | MirScalarExpr::CallVariadic { .. }
+    ) && !expr.contains_nullary()
+        && !expr.support().is_empty()
+}
+
+/// Applies `f` in post-order to the subexpressions of `expr` that are
+/// evaluated whenever `expr` is.
+fn visit_unconditional<'a, F>(expr: &'a MirScalarExpr, f: &mut F)
+where
+    F: FnMut(&'a MirScalarExpr),
+{
+    match expr {
+        MirScalarExpr::If { cond, .. } => visit_unconditional(cond, f),
+        MirScalarExpr::CallBinary {
+            func: BinaryFunc::And | BinaryFunc::Or,
+            expr1,
+            ..
+        } => visit_unconditional(expr1, f),
+        MirScalarExpr::CallVariadic {
+            func: VariadicFunc::Coalesce,
+            exprs,
+        } => {
+            if let Some(first) = exprs.first() {
+                visit_unconditional(first, f);
+            }
+        }
+        _ => expr.visit_children(|child| visit_unconditional(child, f)),
+    }
+    f(expr);
+}
+
+/// Replaces the occurrences of `call` that are evaluated whenever `expr` is
+/// with a reference to `column`.
+fn replace_unconditional(expr: &mut MirScalarExpr, call: &MirScalarExpr, column: usize) {
+    if *expr == *call {
+        *expr = MirScalarExpr::Column(column);
+        return;
+    }
+    match expr {
+        MirScalarExpr::If { cond, .. } => replace_unconditional(cond, call, column),
+        MirScalarExpr::CallBinary {
+            func: BinaryFunc::And | BinaryFunc::Or,
+            expr1,
+            ..
+        } => replace_unconditional(expr1, call, column),
+        MirScalarExpr::CallVariadic {
+            func: VariadicFunc::Coalesce,
+            exprs,
+        } => {
+            if let Some(first) = exprs.first_mut() {
+                replace_unconditional(first, call, column);
+            }
+        }
+        _ => expr.visit_mut_children(|child| replace_unconditional(child, call, column)),
+    }
+}
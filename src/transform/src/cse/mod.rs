@@ -8,4 +8,5 @@
 // by the Apache License, Version 2.0.
 
 //! Common subexpression elimination.
+pub mod filter_cse;
 pub mod relation_cse;
@@ -0,0 +1,116 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Simplifies `Filter` predicates using the literal values that other
+//! predicates in the same `Filter` chain establish for columns.
+//!
+//! A predicate of the form `#c = literal` means that every row surviving the
+//! filter has `#c` equal to `literal`. The other predicates of the filter, and
+//! those of any filter directly above it, need only be correct for such rows,
+//! so they may be evaluated with `literal` in place of `#c`. A predicate that
+//! then reduces to `true` is removed, and one that reduces to `false` or
+//! `null` is replaced by `false`.
+//!
+//! The analysis is bounded to literal equalities. Predicates that do not
+//! reduce to a literal, or that reduce to an error, are left as they were,
+//! as the error might not occur for any row that satisfies the equality.
+
+use mz_expr::{BinaryFunc, MirRelationExpr, MirScalarExpr};
+use mz_repr::{Datum, ScalarType};
+
+use crate::TransformArgs;
+
+/// Simplifies `Filter` predicates using literal equalities from the same
+/// `Filter` chain.
+#[derive(Debug)]
+pub struct EquivalencePropagation;
+
+impl crate::Transform for EquivalencePropagation {
+    fn transform(
+        &self,
+        relation: &mut MirRelationExpr,
+        _: TransformArgs,
+    ) -> Result<(), crate::TransformError> {
+        relation.visit_mut_post(&mut |e| self.action(e));
+        Ok(())
+    }
+}
+
+impl EquivalencePropagation {
+    /// Simplifies `Filter` predicates using literal equalities from the same
+    /// `Filter` chain.
+    pub fn action(&self, relation: &mut MirRelationExpr) {
+        if let MirRelationExpr::Filter { input, predicates } = relation {
+            let mut facts = predicates
+                .iter()
+                .filter_map(literal_equality)
+                .collect::<Vec<_>>();
+            let mut inner = &**input;
+            while let MirRelationExpr::Filter {
+                input,
+                predicates: inner_predicates,
+            } = inner
+            {
+                facts.extend(inner_predicates.iter().filter_map(literal_equality));
+                inner = input;
+            }
+            if facts.is_empty() {
+                return;
+            }
+
+            let input_type = input.typ();
+            for predicate in predicates.iter_mut() {
+                // Predicates that establish a fact are kept as they are.
+                if literal_equality(predicate).is_some() {
+                    continue;
+                }
+                let mut reduced = predicate.clone();
+                reduced.visit_mut_post(&mut |e| {
+                    if let MirScalarExpr::Column(c) = e {
+                        if let Some((_, literal)) = facts.iter().find(|(column, _)| column == c) {
+                            *e = literal.clone();
+                        }
+                    }
+                });
+                reduced.reduce(&input_type);
+                if reduced.is_literal_true() {
+                    *predicate = reduced;
+                } else if reduced.is_literal_false() || reduced.is_literal_null() {
+                    *predicate = MirScalarExpr::literal_ok(Datum::False, ScalarType::Bool);
+                }
+            }
+            predicates.retain(|p| !p.is_literal_true());
+            if predicates.is_empty() {
+                *relation = input.take_dangerous();
+            }
+        }
+    }
+}
+
+/// If `predicate` is `#c = literal` or `literal = #c` for a non-null literal,
+/// returns `c` and the literal.
+fn literal_equality(predicate: &MirScalarExpr) -> Option<(usize, MirScalarExpr)> {
+    if let MirScalarExpr::CallBinary {
+        func: BinaryFunc::Eq,
+        expr1,
+        expr2,
+    } = predicate
+    {
+        let (column, literal) = match (&**expr1, &**expr2) {
+            (MirScalarExpr::Column(c), literal) | (literal, MirScalarExpr::Column(c)) => {
+                (*c, literal)
+            }
+            _ => return None,
+        };
+        if literal.is_literal_ok() && !literal.is_literal_null() {
+            return Some((column, literal.clone()));
+        }
+    }
+    None
+}
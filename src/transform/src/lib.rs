@@ -32,8 +32,10 @@ use mz_ore::id_gen::IdGen;
 
 pub mod canonicalize_mfp;
 pub mod column_knowledge;
+pub mod constant_sharing;
 pub mod cse;
 pub mod demand;
+pub mod equivalence_propagation;
 pub mod fusion;
 pub mod inline_let;
 pub mod join_implementation;
@@ -113,12 +115,31 @@ pub struct Fixpoint {
     limit: usize,
 }
 
-impl Transform for Fixpoint {
-    fn transform(
+impl Fixpoint {
+    /// The maximum number of times the soft `limit` may be extended because
+    /// the relation was still getting simpler. This is a hard cap of
+    /// `limit * MAX_EXTENSIONS` iterations in total.
+    const MAX_EXTENSIONS: usize = 100;
+
+    /// Creates a transform that applies `transforms`, in order, until the
+    /// relation no longer changes.
+    ///
+    /// `limit` is the soft limit on the number of iterations; see
+    /// [`Fixpoint::transform_with_iterations`].
+    pub fn new(transforms: Vec<Box<dyn crate::Transform>>, limit: usize) -> Self {
+        Self { transforms, limit }
+    }
+
+    /// Applies the transforms until the relation reaches a fixpoint, and
+    /// returns the number of iterations that were needed to get there.
+    ///
+    /// An iteration is one pass of every transform over the relation, so a
+    /// relation that was already stable reports a single iteration.
+    pub fn transform_with_iterations(
         &self,
         relation: &mut MirRelationExpr,
         args: TransformArgs,
-    ) -> Result<(), TransformError> {
+    ) -> Result<usize, TransformError> {
         // The number of iterations for a relation to settle depends on the
         // number of nodes in the relation. Instead of picking an arbitrary
         // hard limit on the number of iterations, we use a soft limit and
@@ -126,7 +147,8 @@ impl Transform for Fixpoint {
         // If so, we perform another pass of transforms. Otherwise, there is
         // a bug somewhere that prevents the relation from settling on a
         // stable shape.
-        loop {
+        let mut iterations = 0;
+        for _ in 0..Self::MAX_EXTENSIONS {
             let mut original_count = 0;
             relation.try_visit_post::<_, TransformError>(&mut |_| Ok(original_count += 1))?;
             for _ in 0..self.limit {
@@ -140,8 +162,9 @@ impl Transform for Fixpoint {
                         },
                     )?;
                 }
+                iterations += 1;
                 if *relation == original {
-                    return Ok(());
+                    return Ok(iterations);
                 }
             }
             let mut final_count = 0;
@@ -167,6 +190,16 @@ impl Transform for Fixpoint {
     }
 }
 
+impl Transform for Fixpoint {
+    fn transform(
+        &self,
+        relation: &mut MirRelationExpr,
+        args: TransformArgs,
+    ) -> Result<(), TransformError> {
+        self.transform_with_iterations(relation, args).map(|_| ())
+    }
+}
+
 /// A sequence of transformations that simplify the `MirRelationExpr`
 #[derive(Debug)]
 pub struct FuseAndCollapse {
@@ -258,6 +291,9 @@ impl Optimizer {
             // 1. Structure-agnostic cleanup
             Box::new(crate::topk_elision::TopKElision),
             Box::new(crate::nonnull_requirements::NonNullRequirements::default()),
+            // Shares repeated constant map scalars before they are folded,
+            // so that each is folded once.
+            Box::new(crate::constant_sharing::ConstantSharing),
             // 2. Collapse constants, joins, unions, and lets as much as possible.
             // TODO: lift filters/maps to maximize ability to collapse
             // things down?
@@ -279,6 +315,9 @@ impl Optimizer {
                     // less well than the previous transform. Eliminate
                     // redundancy between the two transforms.
                     Box::new(crate::column_knowledge::ColumnKnowledge::default()),
+                    // Simplifies predicates using the `col = literal`
+                    // predicates of the same filter.
+                    Box::new(crate::equivalence_propagation::EquivalencePropagation),
                     // Lifts the information `col1 = col2`
                     Box::new(crate::demand::Demand::default()),
                     Box::new(crate::FuseAndCollapse::default()),
@@ -305,6 +344,11 @@ impl Optimizer {
                     Box::new(crate::FuseAndCollapse::default()),
                 ],
             }),
+            // 5. Compute function calls repeated across filter predicates
+            //    once. This runs outside of the fixpoints above, as
+            //    predicate pushdown inlines simple calls back into the
+            //    predicates.
+            Box::new(crate::cse::filter_cse::FilterCSE),
         ];
         Self { transforms }
     }
@@ -400,3 +444,122 @@ impl Optimizer {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mz_repr::RelationType;
+
+    /// Removes the outermost `Negate`, if any.
+    #[derive(Debug)]
+    struct StripNegate;
+
+    impl Transform for StripNegate {
+        fn transform(
+            &self,
+            relation: &mut MirRelationExpr,
+            _: TransformArgs,
+        ) -> Result<(), TransformError> {
+            if let MirRelationExpr::Negate { input } = relation {
+                *relation = input.take_dangerous();
+            }
+            Ok(())
+        }
+    }
+
+    /// Wraps the relation in another `Negate`, so that it never settles.
+    #[derive(Debug)]
+    struct WrapNegate;
+
+    impl Transform for WrapNegate {
+        fn transform(
+            &self,
+            relation: &mut MirRelationExpr,
+            _: TransformArgs,
+        ) -> Result<(), TransformError> {
+            *relation = relation.take_dangerous().negate();
+            Ok(())
+        }
+    }
+
+    fn negated(count: usize) -> MirRelationExpr {
+        let mut relation = MirRelationExpr::constant(vec![vec![]], RelationType::empty());
+        for _ in 0..count {
+            relation = relation.negate();
+        }
+        relation
+    }
+
+    fn count_negates(relation: &MirRelationExpr) -> usize {
+        let mut count = 0;
+        relation.visit_post(&mut |e| {
+            if let MirRelationExpr::Negate { .. } = e {
+                count += 1;
+            }
+        });
+        count
+    }
+
+    fn run(
+        transform: impl Transform + 'static,
+        limit: usize,
+        relation: &mut MirRelationExpr,
+    ) -> Result<usize, TransformError> {
+        let fixpoint = Fixpoint::new(vec![Box::new(transform)], limit);
+        fixpoint.transform_with_iterations(
+            relation,
+            TransformArgs {
+                id_gen: &mut Default::default(),
+                indexes: &HashMap::new(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_fixpoint_stable() {
+        let mut relation = negated(0);
+        assert_eq!(run(StripNegate, 10, &mut relation).unwrap(), 1);
+        assert_eq!(relation, negated(0));
+    }
+
+    #[test]
+    fn test_fixpoint_converges_within_limit() {
+        let mut relation = negated(3);
+        assert_eq!(run(StripNegate, 10, &mut relation).unwrap(), 4);
+        assert_eq!(relation, negated(0));
+    }
+
+    #[test]
+    fn test_fixpoint_extends_limit_while_simplifying() {
+        // Each extension of the soft limit shrinks the relation, so the
+        // fixpoint keeps going past the soft limit until it settles.
+        let mut relation = negated(25);
+        assert_eq!(run(StripNegate, 10, &mut relation).unwrap(), 26);
+        assert_eq!(relation, negated(0));
+    }
+
+    #[test]
+    fn test_fixpoint_stops_when_not_simplifying() {
+        let mut relation = negated(0);
+        let err = run(WrapNegate, 10, &mut relation).unwrap_err();
+        assert!(err.to_string().contains("fixpoint looped too many times"));
+        // The soft limit is not extended, as the relation grew. After the
+        // limit, the transforms are applied once more to report the result.
+        assert_eq!(count_negates(&relation), 11);
+    }
+
+    #[test]
+    fn test_fixpoint_extension_limit() {
+        // With a soft limit of one iteration, each extension removes a single
+        // `Negate`, so a relation with more than `MAX_EXTENSIONS` of them
+        // exhausts the extensions before it settles.
+        let negates = Fixpoint::MAX_EXTENSIONS + 10;
+        let mut relation = negated(negates);
+        let err = run(StripNegate, 1, &mut relation).unwrap_err();
+        assert!(err.to_string().contains("fixpoint looped too many times"));
+        assert_eq!(
+            count_negates(&relation),
+            negates - Fixpoint::MAX_EXTENSIONS - 1
+        );
+    }
+}
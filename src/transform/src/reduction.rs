@@ -10,11 +10,14 @@
 //! Replace operators on constants collections with constant collections.
 
 use std::cmp::Ordering;
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::convert::TryInto;
 use std::iter;
 
-use mz_expr::{AggregateExpr, ColumnOrder, EvalError, MirRelationExpr, MirScalarExpr, TableFunc};
+use mz_expr::{
+    func, AggregateExpr, BinaryFunc, ColumnOrder, EvalError, Id, JoinImplementation,
+    MirRelationExpr, MirScalarExpr, TableFunc, UnaryFunc,
+};
 use mz_repr::{Datum, Diff, RelationType, Row, RowArena};
 
 use crate::{TransformArgs, TransformError};
@@ -30,12 +33,50 @@ pub struct FoldConstants {
     pub limit: Option<usize>,
 }
 
+/// The maximum number of rows in a constant `Let` binding that will be
+/// substituted into the binding's uses.
+///
+/// Each use receives its own copy of the constant, so only small constants
+/// are inlined to avoid bloating the plan.
+const INLINE_CONSTANT_LIMIT: usize = 16;
+
 impl crate::Transform for FoldConstants {
     fn transform(
         &self,
         relation: &mut MirRelationExpr,
         _: TransformArgs,
     ) -> Result<(), TransformError> {
+        // Substitute small constant bindings into their uses up front, so that
+        // the bottom-up pass below folds the operators that consume them.
+        // Other bindings are left to InlineLet.
+        let limit = self
+            .limit
+            .map_or(INLINE_CONSTANT_LIMIT, |l| l.min(INLINE_CONSTANT_LIMIT));
+        let mut constants = HashMap::new();
+        relation.try_visit_mut_pre(&mut |e| -> Result<(), TransformError> {
+            loop {
+                match e {
+                    MirRelationExpr::Let { id, value, body }
+                        if matches!(
+                            &**value,
+                            MirRelationExpr::Constant { rows, .. }
+                                if rows.as_ref().map_or(true, |rows| rows.len() <= limit)
+                        ) =>
+                    {
+                        constants.insert(Id::Local(*id), value.take_dangerous());
+                        *e = body.take_dangerous();
+                    }
+                    MirRelationExpr::Get { id, .. } => {
+                        if let Some(value) = constants.get(&*id) {
+                            *e = value.clone();
+                        }
+                        return Ok(());
+                    }
+                    _ => return Ok(()),
+                }
+            }
+        })?;
+
         let mut type_stack = Vec::new();
         relation.try_visit_mut_post(&mut |e| -> Result<(), TransformError> {
             let num_inputs = e.num_inputs();
@@ -64,7 +105,7 @@ impl FoldConstants {
         match relation {
             MirRelationExpr::Constant { .. } => { /* handled after match */ }
             MirRelationExpr::Get { .. } => {}
-            MirRelationExpr::Let { .. } => { /* constant prop done in InlineLet */ }
+            MirRelationExpr::Let { .. } => { /* constant prop done in `transform` and InlineLet */ }
             MirRelationExpr::Reduce {
                 input,
                 group_key,
@@ -107,7 +148,10 @@ impl FoldConstants {
                 offset,
                 ..
             } => {
-                if let MirRelationExpr::Constant { rows, .. } = &mut **input {
+                // A limit of zero retains no records, whatever the input.
+                if *limit == Some(0) {
+                    relation.take_safely();
+                } else if let MirRelationExpr::Constant { rows, .. } = &mut **input {
                     if let Ok(rows) = rows {
                         Self::fold_topk_constant(group_key, order_key, limit, offset, rows);
                     }
@@ -219,10 +263,14 @@ impl FoldConstants {
             }
             MirRelationExpr::Filter { input, predicates } => {
                 let input_typ = input_types.first().unwrap();
+                Self::inline_literal_comparisons(predicates, input);
                 for predicate in predicates.iter_mut() {
                     predicate.reduce(input_typ);
                 }
                 predicates.retain(|p| !p.is_literal_true());
+                // Remove duplicate predicates, keeping the first instance of each.
+                let mut seen = HashSet::new();
+                predicates.retain(|p| seen.insert(p.clone()));
 
                 // Guard against evaluating expression that may contain nullary function calls.
                 if predicates.iter().any(|e| e.contains_nullary()) {
@@ -272,7 +320,7 @@ impl FoldConstants {
             MirRelationExpr::Join {
                 inputs,
                 equivalences,
-                ..
+                implementation,
             } => {
                 if inputs.iter().any(|e| e.is_empty()) {
                     relation.take_safely();
@@ -344,6 +392,47 @@ impl FoldConstants {
                         rows: Ok(old_rows),
                         typ: relation_type.clone(),
                     };
+                } else if inputs.len() == 1
+                    && matches!(implementation, JoinImplementation::Unimplemented)
+                {
+                    // A join of a single input is that input, restricted to the
+                    // rows that satisfy the equivalences. The input's columns
+                    // are the join's columns, so the equivalences need no
+                    // remapping.
+                    //
+                    // Implemented single-input joins are left alone, as
+                    // `JoinImplementation` plans filters that way to look rows
+                    // up in an existing arrangement.
+                    let input_type = &input_types[0];
+                    let mut predicates = Vec::new();
+                    for equivalence in equivalences.iter() {
+                        if let Some((first, rest)) = equivalence.split_first() {
+                            for expr in rest {
+                                let eq = first.clone().call_binary(expr.clone(), BinaryFunc::Eq);
+                                if first.typ(input_type).nullable && expr.typ(input_type).nullable {
+                                    // Unlike `=`, join equivalences match nulls to
+                                    // one another.
+                                    let both_null = first
+                                        .clone()
+                                        .call_unary(UnaryFunc::IsNull(func::IsNull))
+                                        .call_binary(
+                                            expr.clone()
+                                                .call_unary(UnaryFunc::IsNull(func::IsNull)),
+                                            BinaryFunc::And,
+                                        );
+                                    predicates.push(eq.call_binary(both_null, BinaryFunc::Or));
+                                } else {
+                                    predicates.push(eq);
+                                }
+                            }
+                        }
+                    }
+                    let input = inputs.pop().unwrap();
+                    *relation = if predicates.is_empty() {
+                        input
+                    } else {
+                        input.filter(predicates)
+                    };
                 }
                 // TODO: General constant folding for all constant inputs.
             }
@@ -455,8 +544,12 @@ impl FoldConstants {
                 })
                 .collect::<Result<Vec<_>, _>>()?;
             let entry = groups.entry(key).or_insert_with(Vec::new);
-            for _ in 0..*diff {
-                entry.push(val.clone());
+            // Without aggregates there is nothing to accumulate: each key
+            // is emitted once, regardless of the multiplicity of its rows.
+            if !aggregates.is_empty() {
+                for _ in 0..*diff {
+                    entry.push(val.clone());
+                }
             }
         }
 
@@ -602,6 +695,59 @@ impl FoldConstants {
         Ok(Some(new_rows))
     }
 
+    /// Substitutes the literal columns of `input`, if it is a `Map`, into
+    /// comparisons in `predicates` whose operands then both become literals,
+    /// so that the comparisons can be evaluated without a constant input.
+    fn inline_literal_comparisons(predicates: &mut [MirScalarExpr], input: &MirRelationExpr) {
+        if let MirRelationExpr::Map {
+            input: map_input,
+            scalars,
+        } = input
+        {
+            let map_input_arity = map_input.arity();
+            let literal = |c: usize| {
+                c.checked_sub(map_input_arity)
+                    .map(|i| &scalars[i])
+                    .filter(|s| s.is_literal_ok())
+            };
+            for predicate in predicates.iter_mut() {
+                predicate.visit_mut_post(&mut |e| {
+                    if let MirScalarExpr::CallBinary { func, expr1, expr2 } = e {
+                        if !matches!(
+                            func,
+                            BinaryFunc::Eq
+                                | BinaryFunc::NotEq
+                                | BinaryFunc::Lt
+                                | BinaryFunc::Lte
+                                | BinaryFunc::Gt
+                                | BinaryFunc::Gte
+                        ) {
+                            return;
+                        }
+                        let mut operands = [(**expr1).clone(), (**expr2).clone()];
+                        for operand in operands.iter_mut() {
+                            operand.visit_mut_post(&mut |e| {
+                                if let MirScalarExpr::Column(c) = e {
+                                    if let Some(literal) = literal(*c) {
+                                        *e = literal.clone();
+                                    }
+                                }
+                            });
+                        }
+                        if operands
+                            .iter()
+                            .all(|o| o.support().is_empty() && !o.contains_nullary())
+                        {
+                            let [operand1, operand2] = operands;
+                            **expr1 = operand1;
+                            **expr2 = operand2;
+                        }
+                    }
+                });
+            }
+        }
+    }
+
     fn fold_filter_constant(
         predicates: &[MirScalarExpr],
         rows: &[(Row, Diff)],
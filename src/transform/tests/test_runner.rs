@@ -231,7 +231,12 @@ mod tests {
             "ColumnKnowledge" => Ok(Box::new(
                 mz_transform::column_knowledge::ColumnKnowledge::default(),
             )),
+            "ConstantSharing" => Ok(Box::new(mz_transform::constant_sharing::ConstantSharing)),
             "Demand" => Ok(Box::new(mz_transform::demand::Demand::default())),
+            "EquivalencePropagation" => Ok(Box::new(
+                mz_transform::equivalence_propagation::EquivalencePropagation,
+            )),
+            "FilterCSE" => Ok(Box::new(mz_transform::cse::filter_cse::FilterCSE)),
             "FilterFusion" => Ok(Box::new(mz_transform::fusion::filter::Filter)),
             "FoldConstants" => Ok(Box::new(mz_transform::reduction::FoldConstants {
                 limit: None,
@@ -240,6 +245,7 @@ mod tests {
             "LiteralLifting" => Ok(Box::new(
                 mz_transform::map_lifting::LiteralLifting::default(),
             )),
+            "NegateFusion" => Ok(Box::new(mz_transform::fusion::negate::Negate)),
             "NonNullRequirements" => Ok(Box::new(
                 mz_transform::nonnull_requirements::NonNullRequirements::default(),
             )),
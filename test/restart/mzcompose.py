@@ -63,6 +63,22 @@ def workflow_github_8021(c: Composition) -> None:
     c.kill("materialized")
 
 
+def workflow_alter_source_rename_column(c: Composition) -> None:
+    c.start_and_wait_for_tcp(services=["zookeeper", "kafka", "schema-registry"])
+
+    c.up("materialized")
+    c.wait_for_materialized("materialized")
+    c.run("testdrive-svc", "alter-source-rename-column.td")
+
+    # Ensure MZ can replan the renamed source's definition on boot
+    c.kill("materialized")
+    c.up("materialized")
+    c.wait_for_materialized("materialized")
+    c.run("testdrive_no_reset", "alter-source-rename-column-after-restart.td")
+    c.kill("materialized")
+
+
 def workflow_default(c: Composition) -> None:
     workflow_disable_user_indexes(c)
     workflow_github_8021(c)
+    workflow_alter_source_rename_column(c)